@@ -1,19 +1,16 @@
-use std::sync::Arc;
-
-use gloo::{console::log, timers::future::TimeoutFuture};
-use wasm_bindgen::{prelude::Closure, JsValue};
-use wasm_bindgen_futures::{spawn_local, JsFuture};
+use gloo::console::log;
 use yew::prelude::*;
-use youtube_player_api::{init_yt_api, PlayerEvents, PlayerOptions, PlayerVars, YtPlayer};
+use youtube_player_api::{PlaybackQuality, PlayerOptions, PlayerVars, YtPlayer};
+use youtube_player_api_yew::{PlayerRef, YoutubePlayer};
 
 pub enum Msg {
     ActivatePlayer,
-    BindPlayer,
     PlayVideo,
     PauseVideo,
     StopVideo,
     ChangeVideo(String),
     ReadPlayerState,
+    QualityChange(PlaybackQuality),
 }
 
 #[derive(Debug, Default, PartialEq, Properties)]
@@ -23,24 +20,17 @@ pub struct Props {
 
 pub struct App {
     active: bool,
-    player_instance: Arc<Option<YtPlayer>>,
-    // on_ready: Closure<dyn FnMut(JsValue)>,
-    // on_state_change: Closure<dyn FnMut(JsValue)>,
-    // on_quality_change: Closure<dyn FnMut(JsValue)>,
-    // on_error: Closure<dyn FnMut(JsValue)>,
+    video_id: String,
+    player_options: PlayerOptions,
+    player_ref: PlayerRef,
 }
 
 impl App {
-    fn get_player_instance(&self) -> Option<&YtPlayer> {
-        self.player_instance.as_ref().as_ref()
-    }
-
     fn run_player<F>(&self, cb: F)
     where
         F: FnOnce(&YtPlayer),
     {
-        let player_instance_option = self.get_player_instance();
-        player_instance_option.map(cb);
+        self.player_ref.with(cb);
     }
 }
 
@@ -49,85 +39,27 @@ impl Component for App {
     type Properties = Props;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        // load Youtube Player API scripts
-        let _initialized = JsFuture::from(init_yt_api());
-
-        // let handle_ready = self::add_ready_event_handler(|_player_instance: PlayerInstance| {
-        //     log!("player ready");
-        // });
+        let player_vars = PlayerVars::new().autoplay(true).controls(false);
 
-        // let handle_state_change = self::add_state_changed_event_handler(|state: f64| {
-        //     // YT.PlayerState.[STATE]: UNSTARTED: -1, ENDED: 0, PLAYING: 1, PAUSED: 2, BUFFERING: 3, CUED: 5
-        //     log!(format!("player state changed: \"{}\"", state));
-        // });
-
-        // let handle_quality_change = self::add_quality_changed_event_handler(|quality: String| {
-        //     log!(format!("player quality changed: \"{}\"", quality));
-        // });
-
-        // let register_error = self::add_error_event_handler(|error: JsValue| {
-        //     log!(format!("player error: \"{:?}\"", error));
-        // });
+        let player_options = PlayerOptions::new()
+            .aspect_ratio(16.0 / 9.0)
+            .player_vars(player_vars);
 
         Self {
             active: false,
-            player_instance: Arc::new(None),
-            // on_ready: handle_ready,
-            // on_state_change: handle_state_change,
-            // on_quality_change: handle_quality_change,
-            // on_error: register_error,
+            video_id: "cE0wfjsybIQ".to_owned(),
+            player_options,
+            player_ref: PlayerRef::default(),
         }
     }
 
-    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        let link = ctx.link();
-
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::ActivatePlayer => {
                 // Enforce a site interaction, otherwise autoplay could potentially not work.
                 // At least one site interaction is needed to enable automatic player control
                 // for Browsers blocking autoplay by default.
                 self.active = true;
-
-                let cb = link.callback(|_| Msg::BindPlayer);
-
-                // wait for another loop that YT-iFrame can be rendered in page
-                spawn_local(async move {
-                    TimeoutFuture::new(0).await;
-                    cb.emit(0);
-                });
-            }
-            Msg::BindPlayer => {
-                // retrieve player instance, YT-iFrame must already exist
-                let player_vars = PlayerVars::new().autoplay(1).controls(0);
-
-                let player_options = PlayerOptions::new()
-                    .video_id("cE0wfjsybIQ".to_owned())
-                    .width(640)
-                    .height(360)
-                    .player_vars(player_vars);
-
-                let player_instance = YtPlayer::new("yt-player", player_options.into());
-                self.player_instance = Arc::new(Some(player_instance));
-
-                // FIXME handle events, currently not called
-                let quality_change_handler =
-                    self::add_quality_changed_event_handler(|quality: String| {
-                        log!(format!("player quality changed: \"{}\"", quality));
-                    });
-
-                self.player_instance.as_ref().as_ref().unwrap().on(
-                    &PlayerEvents::get_handler_name(PlayerEvents::PLAYBACK_QUALITY_CHANGE).unwrap(),
-                    quality_change_handler.into_js_value(),
-                );
-
-                // bind player events
-                // self.run_player(|instance| {
-                //     instance.on("ready".to_owned(), &self.on_ready);
-                //     instance.on("stateChange".to_owned(), &self.on_state_change);
-                //     instance.on("playbackQualityChange".to_owned(), &self.on_quality_change);
-                //     instance.on("error".to_owned(), &self.on_error);
-                // });
             }
             Msg::PlayVideo => {
                 self.run_player(|instance| instance.play_video());
@@ -139,7 +71,8 @@ impl Component for App {
                 self.run_player(|instance| instance.stop_video());
             }
             Msg::ChangeVideo(video_id) => {
-                self.run_player(|instance| instance.change_video(&video_id));
+                // player loads the new video on its own
+                self.video_id = video_id;
             }
             Msg::ReadPlayerState => {
                 self.run_player(|instance| {
@@ -147,6 +80,11 @@ impl Component for App {
                     log!(format!("current player state: \"{}\"", state));
                 });
 
+                return false;
+            }
+            Msg::QualityChange(quality) => {
+                log!(format!("player quality changed: \"{}\"", quality));
+
                 return false;
             }
         }
@@ -161,9 +99,6 @@ impl Component for App {
         let link = ctx.link();
         let props = ctx.props();
 
-        let frame_url =
-            "http://www.youtube.com/embed/cE0wfjsybIQ?enablejsapi=1&autoplay=1&controls=1";
-
         html! {
             <>
                 <h1>{&props.name}</h1>
@@ -171,15 +106,14 @@ impl Component for App {
                 if !self.active {
                     <button onclick={link.callback(|_| Msg::ActivatePlayer)}>{"Activate Player"}</button>
                 } else {
-                    <iframe id="yt-player" type="text/html"
-                        width="640"
-                        height="360"
-                        frameborder="0"
-                        src={frame_url}
-                        sandbox="allow-scripts allow-same-origin allow-forms allow-popups"
-                        allow="autoplay"
-                        allowfullscreen=true>
-                    </iframe>
+                    <div style="max-width: 640px">
+                        <YoutubePlayer
+                            video_id={Some(self.video_id.clone())}
+                            options={self.player_options.clone()}
+                            player_ref={self.player_ref.clone()}
+                            on_playback_quality_change={link.callback(Msg::QualityChange)}
+                        />
+                    </div>
                 }
                 <br />
                 <br />
@@ -193,65 +127,3 @@ impl Component for App {
         }
     }
 }
-
-// fn add_ready_event_handler<F>(cb: F) -> Closure<dyn FnMut(JsValue)>
-// where
-//     F: 'static,
-//     F: Fn(PlayerInstance),
-// {
-//     Closure::wrap(Box::new(move |event: JsValue| {
-//         #[allow(unused_unsafe)]
-//         let event_target =
-//             unsafe { js_sys::Reflect::get(&event, &wasm_bindgen::JsValue::from_str("target")) };
-
-//         // FIXME find a way to ensure it's a proper player instance
-//         // let yt_player = event_target.and_then(|event_target| event_target.dyn_into::<PlayerInstance>()).ok();
-//         // yt_player.map(|player_instance| cb(player_instance));
-
-//         if let Ok(event_target) = event_target {
-//             cb(event_target.unchecked_into::<PlayerInstance>());
-//         }
-//     }) as Box<dyn FnMut(JsValue)>)
-// }
-
-// fn add_state_changed_event_handler<F>(cb: F) -> Closure<dyn FnMut(JsValue)>
-// where
-//     F: 'static,
-//     F: Fn(f64),
-// {
-//     Closure::wrap(Box::new(move |event: JsValue| {
-//         #[allow(unused_unsafe)]
-//         let event_data =
-//             unsafe { js_sys::Reflect::get(&event, &wasm_bindgen::JsValue::from_str("data")) };
-
-//         if let Ok(event_data) = event_data {
-//             cb(event_data.as_f64().unwrap());
-//         }
-//     }) as Box<dyn FnMut(JsValue)>)
-// }
-
-fn add_quality_changed_event_handler<F>(cb: F) -> Closure<dyn FnMut(JsValue)>
-where
-    F: 'static,
-    F: Fn(String),
-{
-    Closure::wrap(Box::new(move |event: JsValue| {
-        #[allow(unused_unsafe)]
-        let event_data =
-            unsafe { js_sys::Reflect::get(&event, &wasm_bindgen::JsValue::from_str("data")) };
-
-        if let Ok(event_data) = event_data {
-            cb(event_data.as_string().unwrap());
-        }
-    }) as Box<dyn FnMut(JsValue)>)
-}
-
-// fn add_error_event_handler<F>(cb: F) -> Closure<dyn FnMut(JsValue)>
-// where
-//     F: 'static,
-//     F: Fn(JsValue),
-// {
-//     Closure::wrap(Box::new(move |event: JsValue| {
-//         cb(event);
-//     }) as Box<dyn FnMut(JsValue)>)
-// }
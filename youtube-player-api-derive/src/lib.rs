@@ -0,0 +1,762 @@
+//! Derive macro of trait `youtube_player_api::TypeScript`.
+//!
+//! Declarations follow the `serde` attributes `rename`, `rename_all`, `default` and `skip`.
+//! Fields and types serialized differently (`with`, `into`, ...) need an explicit TypeScript type:
+//!
+//! - `#[ts(type = "0 | 1")]` on a field, variant or type overrides its TypeScript type
+//! - `#[ts(skip)]` on a field or variant omits it
+//! - `#[ts(const_enum)]` on an enum declares a `const enum` of the numbers set by `#[ts(value = 1)]`
+//!   or the explicit discriminants of its variants, variants without either are declared by name
+//! - `#[ts(rename_all = "...")]` on a type renames its variants, e.g. if `serde` uses `into`
+//!
+//! Flattened fields are declared as intersection, untagged enums as union of their variants.
+
+#![warn(missing_debug_implementations, rust_2018_idioms)]
+
+use core::fmt::Write;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Attribute, Data, DataEnum, DeriveInput, Error, Expr, ExprLit, ExprUnary,
+    Field, Fields, FieldsNamed, GenericArgument, Ident, Lit, LitStr, PathArguments, Token, Type,
+    UnOp,
+};
+
+#[proc_macro_derive(TypeScript, attributes(ts))]
+pub fn derive_type_script(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match declaration(&input) {
+        Ok(declaration) => implementation(&input.ident, &declaration).into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Relevant `serde` and `ts` attributes of a type, field or variant.
+#[derive(Debug, Default)]
+struct Attributes {
+    rename: Option<String>,
+    rename_all: Option<String>,
+    default: bool,
+    skip: bool,
+    flatten: bool,
+    untagged: bool,
+    ts_type: Option<String>,
+    const_enum: bool,
+    value: Option<i64>,
+}
+
+impl Attributes {
+    fn parse(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+
+        for attribute in attributes {
+            if attribute.path().is_ident("serde") {
+                attribute.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        parsed.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    } else if meta.path.is_ident("rename_all") {
+                        parsed.rename_all = Some(meta.value()?.parse::<LitStr>()?.value());
+                    } else if meta.path.is_ident("default") {
+                        parsed.default = true;
+                    } else if meta.path.is_ident("skip") {
+                        parsed.skip = true;
+                    } else if meta.path.is_ident("flatten") {
+                        parsed.flatten = true;
+                    } else if meta.path.is_ident("untagged") {
+                        parsed.untagged = true;
+                    }
+
+                    // ignore values of all other attributes, e.g. `with = "..."`
+                    if meta.input.peek(Token![=]) {
+                        meta.value()?.parse::<Expr>()?;
+                    }
+
+                    Ok(())
+                })?;
+            } else if attribute.path().is_ident("ts") {
+                attribute.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("type") {
+                        parsed.ts_type = Some(meta.value()?.parse::<LitStr>()?.value());
+                    } else if meta.path.is_ident("skip") {
+                        parsed.skip = true;
+                    } else if meta.path.is_ident("rename_all") {
+                        parsed.rename_all = Some(meta.value()?.parse::<LitStr>()?.value());
+                    } else if meta.path.is_ident("const_enum") {
+                        parsed.const_enum = true;
+                    } else if meta.path.is_ident("value") {
+                        parsed.value = Some(parse_integer(&meta.value()?.parse()?)?);
+                    } else {
+                        return Err(meta.error("unknown `ts` attribute"));
+                    }
+
+                    Ok(())
+                })?;
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn declaration(input: &DeriveInput) -> syn::Result<String> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "generic types aren't supported by `TypeScript`",
+        ));
+    }
+
+    let attributes = Attributes::parse(&input.attrs)?;
+    let name = input.ident.to_string();
+
+    if let Some(ts_type) = attributes.ts_type {
+        return Ok(format!("export type {} = {};", name, ts_type));
+    }
+
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => interface(&name, &attributes, fields),
+            _ => Err(Error::new_spanned(
+                &input.ident,
+                "tuple and unit structs need `#[ts(type = \"...\")]`",
+            )),
+        },
+        Data::Enum(data) if attributes.const_enum => const_enum(&name, &attributes, data),
+        Data::Enum(data) => union(&name, &attributes, data),
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "unions aren't supported by `TypeScript`",
+        )),
+    }
+}
+
+fn interface(name: &str, attributes: &Attributes, fields: &FieldsNamed) -> syn::Result<String> {
+    let mut flattened = vec![];
+    let mut members = String::new();
+
+    for field in &fields.named {
+        let field_attributes = Attributes::parse(&field.attrs)?;
+
+        if field_attributes.skip {
+            continue;
+        }
+
+        if field_attributes.flatten {
+            let ts_type = match field_attributes.ts_type {
+                Some(ts_type) => ts_type,
+                None => ts_type(option_type(&field.ty).unwrap_or(&field.ty))?,
+            };
+
+            flattened.push(if ts_type.contains(' ') {
+                format!("({})", ts_type)
+            } else {
+                ts_type
+            });
+            continue;
+        }
+
+        let _ = writeln!(
+            members,
+            "  {};",
+            member(field, &field_attributes, attributes)?
+        );
+    }
+
+    if flattened.is_empty() {
+        return Ok(format!("export interface {} {{\n{}}}", name, members));
+    }
+
+    // interfaces can't extend unions, flattened fields need an intersection type
+    if !members.is_empty() {
+        flattened.push(format!("{{\n{}}}", members));
+    }
+
+    Ok(format!("export type {} = {};", name, flattened.join(" & ")))
+}
+
+/// Named field as `name?: type`, `container` holds the attributes renaming the field.
+fn member(field: &Field, attributes: &Attributes, container: &Attributes) -> syn::Result<String> {
+    let ident = field
+        .ident
+        .as_ref()
+        .map(Ident::to_string)
+        .unwrap_or_default();
+    let ident = ident.trim_start_matches("r#");
+
+    let field_name = attributes
+        .rename
+        .clone()
+        .unwrap_or_else(|| rename_field(ident, container.rename_all.as_deref()));
+
+    // optional fields can be missing while deserializing
+    let optional = container.default || attributes.default || option_type(&field.ty).is_some();
+
+    let ts_type = match &attributes.ts_type {
+        Some(ts_type) => ts_type.clone(),
+        None => ts_type(option_type(&field.ty).unwrap_or(&field.ty))?,
+    };
+
+    Ok(format!(
+        "{}{}: {}",
+        field_name,
+        if optional { "?" } else { "" },
+        ts_type
+    ))
+}
+
+/// Externally tagged enum, unit variants are serialized as strings.
+fn union(name: &str, attributes: &Attributes, data: &DataEnum) -> syn::Result<String> {
+    if attributes.untagged {
+        return untagged_union(name, data);
+    }
+
+    let mut variants = vec![];
+
+    for variant in &data.variants {
+        let variant_attributes = Attributes::parse(&variant.attrs)?;
+
+        if variant_attributes.skip {
+            continue;
+        }
+
+        let variant_name = variant_attributes.rename.unwrap_or_else(|| {
+            rename_variant(&variant.ident.to_string(), attributes.rename_all.as_deref())
+        });
+
+        let variant_type = match (&variant.fields, variant_attributes.ts_type) {
+            (Fields::Unit, _) => format!("'{}'", variant_name),
+            (_, Some(ts_type)) => format!("{{ {}: {} }}", variant_name, ts_type),
+            (Fields::Unnamed(fields), None) if fields.unnamed.len() == 1 => {
+                format!(
+                    "{{ {}: {} }}",
+                    variant_name,
+                    ts_type(&fields.unnamed[0].ty)?
+                )
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "variants with multiple fields need `#[ts(type = \"...\")]`",
+                ))
+            }
+        };
+
+        variants.push(variant_type);
+    }
+
+    Ok(format!("export type {} = {};", name, variants.join(" | ")))
+}
+
+/// Untagged enum, variants are serialized as their content.
+fn untagged_union(name: &str, data: &DataEnum) -> syn::Result<String> {
+    let mut variants = vec![];
+
+    for variant in &data.variants {
+        let variant_attributes = Attributes::parse(&variant.attrs)?;
+
+        if variant_attributes.skip {
+            continue;
+        }
+
+        let variant_type = match (&variant.fields, &variant_attributes.ts_type) {
+            (_, Some(ts_type)) => ts_type.clone(),
+            (Fields::Unit, None) => "null".to_owned(),
+            (Fields::Unnamed(fields), None) if fields.unnamed.len() == 1 => {
+                ts_type(&fields.unnamed[0].ty)?
+            }
+            (Fields::Named(fields), None) => {
+                let mut members = vec![];
+
+                for field in &fields.named {
+                    let field_attributes = Attributes::parse(&field.attrs)?;
+
+                    if field_attributes.flatten {
+                        return Err(Error::new_spanned(
+                            field,
+                            "flattened fields of variants aren't supported by `TypeScript`",
+                        ));
+                    }
+
+                    if !field_attributes.skip {
+                        members.push(member(field, &field_attributes, &variant_attributes)?);
+                    }
+                }
+
+                format!("{{ {} }}", members.join("; "))
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "variants with multiple fields need `#[ts(type = \"...\")]`",
+                ))
+            }
+        };
+
+        variants.push(variant_type);
+    }
+
+    Ok(format!("export type {} = {};", name, variants.join(" | ")))
+}
+
+fn const_enum(name: &str, attributes: &Attributes, data: &DataEnum) -> syn::Result<String> {
+    let mut declaration = format!("export const enum {} {{\n", name);
+
+    for variant in &data.variants {
+        let variant_attributes = Attributes::parse(&variant.attrs)?;
+
+        if variant_attributes.skip {
+            continue;
+        }
+
+        let value = match (variant_attributes.value, &variant.discriminant) {
+            (Some(value), _) => value.to_string(),
+            (None, Some((_, discriminant))) => parse_integer(discriminant)?.to_string(),
+            (None, None) if variant.fields.is_empty() => {
+                let variant_name = variant_attributes.rename.unwrap_or_else(|| {
+                    rename_variant(&variant.ident.to_string(), attributes.rename_all.as_deref())
+                });
+
+                format!("'{}'", variant_name)
+            }
+            (None, None) => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "variants of const enums need `#[ts(value = ...)]` or a discriminant",
+                ))
+            }
+        };
+
+        let _ = writeln!(
+            declaration,
+            "  {} = {},",
+            screaming_snake_case(&variant.ident.to_string()),
+            value
+        );
+    }
+
+    declaration.push('}');
+
+    Ok(declaration)
+}
+
+fn implementation(ident: &Ident, declaration: &str) -> TokenStream2 {
+    let name = ident.to_string();
+    let section = format_ident!("{}_TYPESCRIPT", screaming_snake_case(&name));
+    let section_content = format!("\n{}\n", declaration);
+
+    quote! {
+        impl ::youtube_player_api::TypeScript for #ident {
+            const NAME: &'static str = #name;
+            const DECLARATION: &'static str = #declaration;
+        }
+
+        #[::wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
+        const #section: &'static str = #section_content;
+    }
+}
+
+fn ts_type(ty: &Type) -> syn::Result<String> {
+    let unsupported = || {
+        Error::new_spanned(
+            ty,
+            "type isn't supported by `TypeScript`, use `#[ts(type = \"...\")]`",
+        )
+    };
+
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last().ok_or_else(unsupported)?;
+
+            match segment.ident.to_string().as_str() {
+                "String" | "str" | "char" => Ok("string".to_owned()),
+                "bool" => Ok("boolean".to_owned()),
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+                | "u128" | "usize" | "f32" | "f64" => Ok("number".to_owned()),
+                "Option" | "Box" | "Rc" | "Arc" => {
+                    ts_type(generic_type(&segment.arguments).ok_or_else(unsupported)?)
+                }
+                "Vec" | "VecDeque" => Ok(array_type(ts_type(
+                    generic_type(&segment.arguments).ok_or_else(unsupported)?,
+                )?)),
+                // referenced types have to derive `TypeScript` as well
+                name => Ok(name.to_owned()),
+            }
+        }
+        Type::Reference(reference) => ts_type(&reference.elem),
+        Type::Slice(slice) => Ok(array_type(ts_type(&slice.elem)?)),
+        Type::Array(array) => Ok(array_type(ts_type(&array.elem)?)),
+        _ => Err(unsupported()),
+    }
+}
+
+fn array_type(element_type: String) -> String {
+    if element_type.contains(' ') {
+        format!("({})[]", element_type)
+    } else {
+        format!("{}[]", element_type)
+    }
+}
+
+/// Inner type of `Option<T>`.
+fn option_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+
+            if segment.ident == "Option" {
+                generic_type(&segment.arguments)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn generic_type(arguments: &PathArguments) -> Option<&Type> {
+    match arguments {
+        PathArguments::AngleBracketed(arguments) => {
+            arguments.args.iter().find_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn parse_integer(expr: &Expr) -> syn::Result<i64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_integer(expr).map(|value| -value),
+        _ => Err(Error::new_spanned(expr, "expected an integer literal")),
+    }
+}
+
+/// Field name after `#[serde(rename_all = "...")]`, fields are written in snake case.
+fn rename_field(field: &str, rule: Option<&str>) -> String {
+    match rule {
+        Some("lowercase") | Some("snake_case") => field.to_owned(),
+        Some("UPPERCASE") | Some("SCREAMING_SNAKE_CASE") => field.to_ascii_uppercase(),
+        Some("camelCase") => camel_case(field, false),
+        Some("PascalCase") => camel_case(field, true),
+        Some("kebab-case") => field.replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => field.replace('_', "-").to_ascii_uppercase(),
+        _ => field.to_owned(),
+    }
+}
+
+/// Variant name after `#[serde(rename_all = "...")]`, variants are written in pascal case.
+fn rename_variant(variant: &str, rule: Option<&str>) -> String {
+    match rule {
+        Some("lowercase") => variant.to_ascii_lowercase(),
+        Some("UPPERCASE") => variant.to_ascii_uppercase(),
+        Some("camelCase") => {
+            let mut chars = variant.chars();
+
+            chars
+                .next()
+                .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+        Some("snake_case") => snake_case(variant),
+        Some("SCREAMING_SNAKE_CASE") => screaming_snake_case(variant),
+        Some("kebab-case") => snake_case(variant).replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => screaming_snake_case(variant).replace('_', "-"),
+        _ => variant.to_owned(),
+    }
+}
+
+fn camel_case(field: &str, capitalize_first: bool) -> String {
+    let mut camel_case = String::new();
+    let mut capitalize = capitalize_first;
+
+    for char in field.chars() {
+        if char == '_' {
+            capitalize = true;
+        } else if capitalize {
+            camel_case.push(char.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            camel_case.push(char);
+        }
+    }
+
+    camel_case
+}
+
+fn snake_case(variant: &str) -> String {
+    let mut snake_case = String::new();
+
+    for (index, char) in variant.char_indices() {
+        if index > 0 && char.is_uppercase() {
+            snake_case.push('_');
+        }
+
+        snake_case.push(char.to_ascii_lowercase());
+    }
+
+    snake_case
+}
+
+fn screaming_snake_case(variant: &str) -> String {
+    snake_case(variant).to_ascii_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn derive(input: TokenStream2) -> syn::Result<String> {
+        declaration(&syn::parse2(input).unwrap())
+    }
+
+    #[test]
+    fn derive_interface() {
+        let declaration = derive(quote! {
+            #[serde(rename_all = "camelCase")]
+            struct Options {
+                video_id: Option<String>,
+                #[serde(rename = "hl")]
+                interface_language: Option<String>,
+                #[serde(with = "numeric_bool", skip_serializing_if = "Option::is_none")]
+                #[ts(type = "0 | 1")]
+                autoplay: Option<bool>,
+                playlist: Vec<String>,
+                #[serde(skip)]
+                internal: u32,
+                r#loop: bool,
+            }
+        });
+
+        assert_eq!(
+            "export interface Options {\n  videoId?: string;\n  hl?: string;\n  autoplay?: 0 | 1;\n  playlist: string[];\n  loop: boolean;\n}",
+            declaration.unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_default_interface() {
+        let declaration = derive(quote! {
+            #[serde(default)]
+            struct Monitor {
+                interval: u32,
+                #[serde(rename = "seekThreshold")]
+                seek_threshold: f64,
+            }
+        });
+
+        assert_eq!(
+            "export interface Monitor {\n  interval?: number;\n  seekThreshold?: number;\n}",
+            declaration.unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_union() {
+        let declaration = derive(quote! {
+            #[serde(rename_all = "camelCase")]
+            enum Target {
+                BeforeFirstScript,
+                Head,
+                Element(String),
+            }
+        });
+
+        assert_eq!(
+            "export type Target = 'beforeFirstScript' | 'head' | { element: string };",
+            declaration.unwrap()
+        );
+
+        let declaration = derive(quote! {
+            #[serde(rename_all = "snake_case")]
+            enum ListType {
+                Playlist,
+                UserUploads,
+            }
+        });
+
+        assert_eq!(
+            "export type ListType = 'playlist' | 'user_uploads';",
+            declaration.unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_const_enum() {
+        let declaration = derive(quote! {
+            #[ts(const_enum)]
+            enum State {
+                #[ts(value = -1)]
+                Unstarted,
+                #[ts(value = 1)]
+                Playing,
+                #[ts(skip)]
+                Unknown(i32),
+            }
+        });
+
+        assert_eq!(
+            "export const enum State {\n  UNSTARTED = -1,\n  PLAYING = 1,\n}",
+            declaration.unwrap()
+        );
+
+        let declaration = derive(quote! {
+            #[ts(const_enum)]
+            enum Policy {
+                Show = 1,
+                Hide = 3,
+            }
+        });
+
+        assert_eq!(
+            "export const enum Policy {\n  SHOW = 1,\n  HIDE = 3,\n}",
+            declaration.unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_string_const_enum() {
+        let declaration = derive(quote! {
+            #[serde(rename_all = "camelCase")]
+            #[ts(const_enum)]
+            enum EventName {
+                Ready,
+                StateChange,
+                #[serde(rename = "*")]
+                Wildcard,
+            }
+        });
+
+        assert_eq!(
+            "export const enum EventName {\n  READY = 'ready',\n  STATE_CHANGE = 'stateChange',\n  WILDCARD = '*',\n}",
+            declaration.unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_untagged_union() {
+        let declaration = derive(quote! {
+            #[serde(untagged)]
+            enum Source {
+                Id {
+                    #[serde(rename = "videoId")]
+                    video_id: String,
+                },
+                #[serde(rename_all = "camelCase")]
+                List { list_type: ListType, list: String },
+                Url(String),
+                None,
+            }
+        });
+
+        assert_eq!(
+            "export type Source = { videoId: string } | { listType: ListType; list: string } | string | null;",
+            declaration.unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_flattened() {
+        let declaration = derive(quote! {
+            #[serde(rename_all = "camelCase")]
+            struct Request {
+                #[serde(flatten)]
+                source: Source,
+                start_seconds: Option<f64>,
+            }
+        });
+
+        assert_eq!(
+            "export type Request = Source & {\n  startSeconds?: number;\n};",
+            declaration.unwrap()
+        );
+
+        let declaration = derive(quote! {
+            struct Request {
+                #[serde(flatten)]
+                #[ts(type = "{ id: string } | { url: string }")]
+                source: Source,
+            }
+        });
+
+        assert_eq!(
+            "export type Request = ({ id: string } | { url: string });",
+            declaration.unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_renamed_variants() {
+        let declaration = derive(quote! {
+            #[serde(from = "String", into = "String")]
+            #[ts(rename_all = "lowercase")]
+            enum Quality {
+                Hd720,
+                HighRes,
+                #[ts(skip)]
+                Unknown(String),
+            }
+        });
+
+        assert_eq!(
+            "export type Quality = 'hd720' | 'highres';",
+            declaration.unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_type_override() {
+        let declaration = derive(quote! {
+            #[serde(from = "f64", into = "u8")]
+            #[ts(type = "number")]
+            struct Volume(u8);
+        });
+
+        assert_eq!("export type Volume = number;", declaration.unwrap());
+    }
+
+    #[test]
+    fn derive_unsupported() {
+        assert!(derive(quote! { struct Volume(u8); }).is_err());
+        assert!(derive(quote! {
+            #[serde(untagged)]
+            enum Source {
+                Id(String, u32),
+            }
+        })
+        .is_err());
+        assert!(derive(quote! {
+            #[serde(untagged)]
+            enum Source {
+                Id {
+                    #[serde(flatten)]
+                    id: Id,
+                },
+            }
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn rename_rules() {
+        assert_eq!("videoId", rename_field("video_id", Some("camelCase")));
+        assert_eq!("VideoId", rename_field("video_id", Some("PascalCase")));
+        assert_eq!("video-id", rename_field("video_id", Some("kebab-case")));
+        assert_eq!("drop", rename_variant("Drop", Some("lowercase")));
+        assert_eq!(
+            "user_uploads",
+            rename_variant("UserUploads", Some("snake_case"))
+        );
+        assert_eq!("USER_UPLOADS", screaming_snake_case("UserUploads"));
+    }
+}
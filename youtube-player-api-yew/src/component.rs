@@ -0,0 +1,179 @@
+use web_sys::{console, Element};
+use yew::prelude::*;
+use youtube_player_api::{
+    PlaybackQuality, PlayerError, PlayerEvent, PlayerOptions, PlayerState, Seek, Stall,
+    VideoRequest, VolumeChange,
+};
+
+use crate::{
+    mount::{load_api, mount_player},
+    PlayerRef,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Properties)]
+pub struct YoutubePlayerProps {
+    /// Video of the player, changing it loads the new video.
+    #[prop_or_default]
+    pub video_id: Option<String>,
+    /// Options of the created player, changes after the player was created are ignored.
+    #[prop_or_default]
+    pub options: PlayerOptions,
+    /// Set to the created player, see `PlayerRef`.
+    #[prop_or_default]
+    pub player_ref: PlayerRef,
+    /// Classes of the element containing the player iframe.
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub on_ready: Callback<()>,
+    #[prop_or_default]
+    pub on_state_change: Callback<PlayerState>,
+    #[prop_or_default]
+    pub on_error: Callback<PlayerError>,
+    #[prop_or_default]
+    pub on_playback_quality_change: Callback<PlaybackQuality>,
+    #[prop_or_default]
+    pub on_playback_rate_change: Callback<f64>,
+    #[prop_or_default]
+    pub on_api_change: Callback<()>,
+    #[prop_or_default]
+    pub on_volume_change: Callback<VolumeChange>,
+    /// Needs option `timeUpdateInterval`.
+    #[prop_or_default]
+    pub on_time_update: Callback<f64>,
+    /// Needs option `timeUpdateInterval`.
+    #[prop_or_default]
+    pub on_progress: Callback<f64>,
+    /// Needs option `playbackMonitor`.
+    #[prop_or_default]
+    pub on_seeked: Callback<Seek>,
+    /// Needs option `playbackMonitor`.
+    #[prop_or_default]
+    pub on_stalled: Callback<Stall>,
+}
+
+#[derive(Debug)]
+pub enum Msg {
+    ApiLoaded,
+    Event(PlayerEvent),
+}
+
+/// Youtube player, created once the Youtube API is loaded and destroyed on unmount.
+///
+/// ```ignore
+/// html! {
+///     <YoutubePlayer
+///         video_id={Some("M7lc1UVf-VE".to_owned())}
+///         player_ref={self.player_ref.clone()}
+///         on_state_change={link.callback(Msg::StateChange)}
+///     />
+/// }
+/// ```
+#[derive(Debug)]
+pub struct YoutubePlayer {
+    container: NodeRef,
+    player: PlayerRef,
+    video_id: Option<String>,
+}
+
+impl YoutubePlayer {
+    fn create_player(&mut self, ctx: &Context<Self>) {
+        // container is rendered before the API can be loaded
+        let container = match self.container.cast::<Element>() {
+            Some(container) => container,
+            None => return,
+        };
+
+        let props = ctx.props();
+        let mut options = props.options.clone();
+
+        if props.video_id.is_some() {
+            options.video_id = props.video_id.clone();
+        }
+
+        let link = ctx.link().clone();
+        let player = mount_player(&container, options, move |event| {
+            link.send_message(Msg::Event(event));
+        });
+
+        self.video_id = props.video_id.clone();
+        self.player.set(player);
+    }
+
+    fn emit_event(props: &YoutubePlayerProps, event: PlayerEvent) {
+        match event {
+            PlayerEvent::Ready => props.on_ready.emit(()),
+            PlayerEvent::StateChange(state) => props.on_state_change.emit(state),
+            PlayerEvent::Error(error) => props.on_error.emit(error),
+            PlayerEvent::QualityChange(quality) => props.on_playback_quality_change.emit(quality),
+            PlayerEvent::RateChange(rate) => props.on_playback_rate_change.emit(rate),
+            PlayerEvent::ApiChange => props.on_api_change.emit(()),
+            PlayerEvent::VolumeChange(volume_change) => props.on_volume_change.emit(volume_change),
+            PlayerEvent::TimeUpdate(current_time) => props.on_time_update.emit(current_time),
+            PlayerEvent::Progress(loaded_fraction) => props.on_progress.emit(loaded_fraction),
+            PlayerEvent::Seeked(seek) => props.on_seeked.emit(seek),
+            PlayerEvent::Stalled(stall) => props.on_stalled.emit(stall),
+            _ => {}
+        }
+    }
+}
+
+impl Component for YoutubePlayer {
+    type Message = Msg;
+    type Properties = YoutubePlayerProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let link = ctx.link().clone();
+        load_api(move || link.send_message(Msg::ApiLoaded));
+
+        Self {
+            container: NodeRef::default(),
+            player: ctx.props().player_ref.clone(),
+            video_id: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ApiLoaded => self.create_player(ctx),
+            Msg::Event(event) => Self::emit_event(ctx.props(), event),
+        }
+
+        false
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        let props = ctx.props();
+
+        // move the player to the new reference
+        if props.player_ref != self.player {
+            props.player_ref.set(self.player.take());
+            self.player = props.player_ref.clone();
+        }
+
+        if props.video_id != self.video_id && self.player.is_set() {
+            if let Some(video_id) = &props.video_id {
+                let request = VideoRequest::by_id(video_id.clone());
+
+                if let Some(Err(error)) = self.player.with(|player| player.load_video(&request)) {
+                    console::error_1(&error.to_string().into());
+                }
+            }
+
+            self.video_id = props.video_id.clone();
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class={ctx.props().class.clone()} ref={self.container.clone()}></div>
+        }
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        // dropping the player destroys it
+        self.player.take();
+    }
+}
@@ -0,0 +1,340 @@
+use std::{cell::Cell, fmt, rc::Rc};
+
+use web_sys::Element;
+use yew::prelude::*;
+use youtube_player_api::{PlayerEvent, PlayerOptions, PlayerState, Volume, VolumeChange, YtPlayer};
+
+use crate::{
+    mount::{load_api, mount_player},
+    PlayerRef,
+};
+
+/// Status of the player, updated by its events.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlayerStatus {
+    pub ready: bool,
+    pub state: PlayerState,
+    /// Seconds, updated by state changes, `seeked` events and `timeUpdate` events if enabled.
+    pub current_time: f64,
+    /// Seconds, `0` until the video metadata has been loaded.
+    pub duration: f64,
+    pub volume: Volume,
+    pub muted: bool,
+}
+
+/// Status update of `PlayerStatus`, derived from a player event.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatusAction {
+    Ready {
+        duration: f64,
+        volume: Volume,
+        muted: bool,
+    },
+    StateChange {
+        state: PlayerState,
+        duration: f64,
+        current_time: f64,
+    },
+    TimeUpdate(f64),
+    VolumeChange(VolumeChange),
+}
+
+impl StatusAction {
+    /// Action of a player event, the player provides values missing in the event.
+    fn from_event(event: PlayerEvent, player: &YtPlayer) -> Option<Self> {
+        let action = match event {
+            PlayerEvent::Ready => Self::Ready {
+                duration: player.duration(),
+                volume: player.volume(),
+                muted: player.is_muted(),
+            },
+            // duration is known once a video is cued or playing
+            PlayerEvent::StateChange(state) => Self::StateChange {
+                state,
+                duration: player.duration(),
+                current_time: player.current_time(),
+            },
+            PlayerEvent::TimeUpdate(current_time) => Self::TimeUpdate(current_time),
+            PlayerEvent::Seeked(seek) => Self::TimeUpdate(seek.to),
+            PlayerEvent::VolumeChange(volume_change) => Self::VolumeChange(volume_change),
+            _ => return None,
+        };
+
+        Some(action)
+    }
+}
+
+impl Reducible for PlayerStatus {
+    type Action = StatusAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut status = (*self).clone();
+
+        match action {
+            StatusAction::Ready {
+                duration,
+                volume,
+                muted,
+            } => {
+                status.ready = true;
+                status.duration = duration;
+                status.volume = volume;
+                status.muted = muted;
+            }
+            StatusAction::StateChange {
+                state,
+                duration,
+                current_time,
+            } => {
+                status.state = state;
+                status.duration = duration;
+
+                // a new video starts at its own position
+                status.current_time = match state {
+                    PlayerState::Unstarted | PlayerState::Cued => 0.0,
+                    _ => current_time,
+                };
+            }
+            StatusAction::TimeUpdate(current_time) => status.current_time = current_time,
+            StatusAction::VolumeChange(volume_change) => {
+                status.volume = volume_change.volume;
+                status.muted = volume_change.muted;
+            }
+        }
+
+        status.into()
+    }
+}
+
+/// Player created by `use_youtube_player`, the component re-renders if its status changes.
+#[derive(Clone)]
+pub struct UseYoutubePlayerHandle {
+    node_ref: NodeRef,
+    player: PlayerRef,
+    status: UseReducerHandle<PlayerStatus>,
+}
+
+impl UseYoutubePlayerHandle {
+    /// Reference of the element the player gets created in, e.g. `<div ref={player.node_ref()} />`.
+    pub fn node_ref(&self) -> NodeRef {
+        self.node_ref.clone()
+    }
+
+    /// Player commands, e.g. `player.player().with(YtPlayer::play_video)`.
+    pub fn player(&self) -> &PlayerRef {
+        &self.player
+    }
+
+    pub fn status(&self) -> &PlayerStatus {
+        &self.status
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status.ready
+    }
+
+    pub fn state(&self) -> PlayerState {
+        self.status.state
+    }
+
+    pub fn current_time(&self) -> f64 {
+        self.status.current_time
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.status.duration
+    }
+
+    pub fn volume(&self) -> Volume {
+        self.status.volume
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.status.muted
+    }
+}
+
+impl fmt::Debug for UseYoutubePlayerHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseYoutubePlayerHandle")
+            .field("player", &self.player)
+            .field("status", self.status())
+            .finish()
+    }
+}
+
+/// Create a player in the element referenced by `node_ref()` once the component is mounted.
+///
+/// The player is destroyed on unmount, changes of `options` afterwards are ignored.
+/// Current time is updated on state changes and `seeked` events (option `playbackMonitor`),
+/// set option `timeUpdateInterval` to update it continuously while playing.
+///
+/// ```ignore
+/// #[function_component(Player)]
+/// fn player() -> Html {
+///     let player = use_youtube_player(PlayerOptions::new().video_id("M7lc1UVf-VE".to_owned()));
+///     let play = {
+///         let player = player.clone();
+///         Callback::from(move |_| {
+///             player.player().with(YtPlayer::play_video);
+///         })
+///     };
+///
+///     html! {
+///         <>
+///             <div ref={player.node_ref()} />
+///             <button onclick={play}>{"play"}</button>
+///             <p>{format!("{} / {}", player.current_time(), player.duration())}</p>
+///         </>
+///     }
+/// }
+/// ```
+pub fn use_youtube_player(options: PlayerOptions) -> UseYoutubePlayerHandle {
+    let node_ref = use_node_ref();
+    let player = (*use_state(PlayerRef::default)).clone();
+    let status = use_reducer_eq(PlayerStatus::default);
+
+    {
+        let node_ref = node_ref.clone();
+        let player = player.clone();
+        let dispatcher = status.dispatcher();
+
+        use_effect_with_deps(
+            move |_| {
+                let unmounted = Rc::new(Cell::new(false));
+
+                let is_unmounted = unmounted.clone();
+                let mount_player_ref = player.clone();
+
+                load_api(move || {
+                    let container = match node_ref.cast::<Element>() {
+                        Some(container) if !is_unmounted.get() => container,
+                        _ => return,
+                    };
+
+                    // handlers only hold the reference, which is cleared on unmount
+                    let handler_player_ref = mount_player_ref.clone();
+
+                    let player = mount_player(&container, options, move |event| {
+                        let action = handler_player_ref
+                            .with(|player| StatusAction::from_event(event, player))
+                            .flatten();
+
+                        if let Some(action) = action {
+                            dispatcher.dispatch(action);
+                        }
+                    });
+
+                    mount_player_ref.set(player);
+                });
+
+                move || {
+                    unmounted.set(true);
+
+                    // dropping the player destroys it
+                    player.take();
+                }
+            },
+            (),
+        );
+    }
+
+    UseYoutubePlayerHandle {
+        node_ref,
+        player,
+        status,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reduce(status: PlayerStatus, actions: Vec<StatusAction>) -> PlayerStatus {
+        let status = actions
+            .into_iter()
+            .fold(Rc::new(status), |status, action| status.reduce(action));
+
+        (*status).clone()
+    }
+
+    #[test]
+    fn status_ready() {
+        let status = reduce(
+            PlayerStatus::default(),
+            vec![StatusAction::Ready {
+                duration: 212.0,
+                volume: Volume::new(80),
+                muted: true,
+            }],
+        );
+
+        assert!(status.ready);
+        assert_eq!(212.0, status.duration);
+        assert_eq!(Volume::new(80), status.volume);
+        assert!(status.muted);
+        assert_eq!(PlayerState::Unstarted, status.state);
+    }
+
+    #[test]
+    fn status_playback() {
+        let status = reduce(
+            PlayerStatus::default(),
+            vec![
+                StatusAction::StateChange {
+                    state: PlayerState::Playing,
+                    duration: 212.0,
+                    current_time: 3.0,
+                },
+                StatusAction::TimeUpdate(12.5),
+                StatusAction::VolumeChange(VolumeChange {
+                    volume: Volume::new(20),
+                    muted: false,
+                }),
+            ],
+        );
+
+        assert_eq!(PlayerState::Playing, status.state);
+        assert_eq!(12.5, status.current_time);
+        assert_eq!(Volume::new(20), status.volume);
+
+        // next video
+        let status = reduce(
+            status,
+            vec![StatusAction::StateChange {
+                state: PlayerState::Unstarted,
+                duration: 0.0,
+                current_time: 20.0,
+            }],
+        );
+
+        assert_eq!(0.0, status.current_time);
+        assert_eq!(0.0, status.duration);
+    }
+
+    #[test]
+    fn status_time_without_time_updates() {
+        let status = reduce(
+            PlayerStatus::default(),
+            vec![
+                StatusAction::StateChange {
+                    state: PlayerState::Playing,
+                    duration: 212.0,
+                    current_time: 0.0,
+                },
+                StatusAction::StateChange {
+                    state: PlayerState::Paused,
+                    duration: 212.0,
+                    current_time: 42.5,
+                },
+            ],
+        );
+
+        assert_eq!(42.5, status.current_time);
+
+        // `seeked` event
+        let status = reduce(status, vec![StatusAction::TimeUpdate(90.0)]);
+
+        assert_eq!(90.0, status.current_time);
+    }
+}
@@ -0,0 +1,12 @@
+//! Yew bindings of crate `youtube-player-api`.
+
+#![warn(missing_debug_implementations, rust_2018_idioms)]
+
+mod component;
+mod hook;
+mod mount;
+mod player_ref;
+
+pub use component::{YoutubePlayer, YoutubePlayerProps};
+pub use hook::{use_youtube_player, PlayerStatus, UseYoutubePlayerHandle};
+pub use player_ref::PlayerRef;
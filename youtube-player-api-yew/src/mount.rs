@@ -0,0 +1,39 @@
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{console, Element};
+use youtube_player_api::{init_yt_api, PlayerEvent, PlayerEvents, PlayerOptions, YtPlayer};
+
+/// Load the Youtube API scripts if necessary, they are shared by all players.
+pub(crate) fn load_api<F>(on_loaded: F)
+where
+    F: FnOnce() + 'static,
+{
+    spawn_local(async move {
+        match JsFuture::from(init_yt_api()).await {
+            Ok(_yt_global) => on_loaded(),
+            Err(error) => console::error_1(&error),
+        }
+    });
+}
+
+/// Create a player inside `parent`, which receives all events of the player.
+pub(crate) fn mount_player<F>(
+    parent: &Element,
+    options: PlayerOptions,
+    handler: F,
+) -> Option<YtPlayer>
+where
+    F: FnMut(PlayerEvent) + 'static,
+{
+    // the API replaces the target element, so it must not be rendered by Yew
+    let player = match YtPlayer::in_container(parent, options.into()) {
+        Ok(player) => player,
+        Err(error) => {
+            console::error_1(&error.to_string().into());
+            return None;
+        }
+    };
+
+    player.on_event(PlayerEvents::WILDCARD, handler);
+
+    Some(player)
+}
@@ -0,0 +1,62 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use youtube_player_api::YtPlayer;
+
+/// Access to the player of a `<YoutubePlayer>` component, similar to a `NodeRef`.
+///
+/// The component sets the player once the Youtube API is loaded and destroys it on unmount.
+#[derive(Clone, Default)]
+pub struct PlayerRef(Rc<RefCell<Option<YtPlayer>>>);
+
+impl PlayerRef {
+    /// Run `f` with the player, `None` if there's no player (yet).
+    ///
+    /// Commands issued before the player is ready are queued, see `PendingCommandPolicy`.
+    pub fn with<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&YtPlayer) -> R,
+    {
+        self.0.borrow().as_ref().map(f)
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.0.borrow().is_some()
+    }
+
+    pub(crate) fn set(&self, player: Option<YtPlayer>) -> Option<YtPlayer> {
+        self.0.replace(player)
+    }
+
+    pub(crate) fn take(&self) -> Option<YtPlayer> {
+        self.0.borrow_mut().take()
+    }
+}
+
+impl PartialEq for PlayerRef {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for PlayerRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PlayerRef")
+            .field("player", &self.0.borrow().as_ref().map(YtPlayer::id))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn player_ref_equality() {
+        let player_ref = PlayerRef::default();
+
+        assert_eq!(player_ref, player_ref.clone());
+        assert_ne!(player_ref, PlayerRef::default());
+        assert!(!player_ref.is_set());
+        assert_eq!(None, player_ref.with(|player| player.id()));
+    }
+}
@@ -0,0 +1,87 @@
+use js_sys::{Function, Object, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+use crate::ApiLoadError;
+
+/// Load of the Youtube API, resolved once and awaitable by any number of callers.
+///
+/// Backed by a JS object, so the load can be kept in the loader state on `window`
+/// instead of a Rust static: member `result` is `true` when loaded or the code of an
+/// `ApiLoadError`, member `promise` resolves once `result` is set.
+#[derive(Clone, Debug)]
+pub(crate) struct ApiLoad(Object);
+
+impl ApiLoad {
+    pub(crate) fn new() -> Self {
+        let load = Object::new();
+
+        let promise = Promise::new(&mut |resolve, _reject| {
+            let _success = Reflect::set(&load, &"resolve".into(), &resolve);
+        });
+        let _success = Reflect::set(&load, &"promise".into(), &promise);
+
+        Self(load)
+    }
+
+    /// Load stored in the loader state, `None` if `value` isn't a load.
+    pub(crate) fn from_js(value: JsValue) -> Option<Self> {
+        let load = value.dyn_into::<Object>().ok()?;
+        let promise = Reflect::get(&load, &"promise".into()).ok()?;
+
+        promise.is_instance_of::<Promise>().then(|| Self(load))
+    }
+
+    pub(crate) fn as_js(&self) -> &JsValue {
+        &self.0
+    }
+
+    /// Resolve load and wake all waiting callers, only the first result is kept.
+    pub(crate) fn resolve(&self, result: Result<(), ApiLoadError>) {
+        if self.value().is_some() {
+            return;
+        }
+
+        let result = match result {
+            Ok(()) => JsValue::from(true),
+            Err(error) => error.code().into(),
+        };
+        let _success = Reflect::set(&self.0, &"result".into(), &result);
+
+        let resolve = Reflect::get(&self.0, &"resolve".into())
+            .ok()
+            .and_then(|resolve| resolve.dyn_into::<Function>().ok());
+
+        if let Some(resolve) = resolve {
+            let _result = resolve.call0(&JsValue::undefined());
+        }
+    }
+
+    /// Result of a resolved load, `None` while still loading.
+    pub(crate) fn value(&self) -> Option<Result<(), ApiLoadError>> {
+        let result = Reflect::get(&self.0, &"result".into()).ok()?;
+
+        if result.is_undefined() {
+            return None;
+        }
+
+        match result.as_f64() {
+            Some(code) => Some(Err(
+                ApiLoadError::from_code(code as u8).unwrap_or(ApiLoadError::YtGlobalMissing)
+            )),
+            None => Some(Ok(())),
+        }
+    }
+
+    pub(crate) async fn wait(&self) -> Result<(), ApiLoadError> {
+        if let Some(result) = self.value() {
+            return result;
+        }
+
+        if let Ok(promise) = Reflect::get(&self.0, &"promise".into()) {
+            let _result = JsFuture::from(promise.unchecked_into::<Promise>()).await;
+        }
+
+        self.value().unwrap_or(Err(ApiLoadError::YtGlobalMissing))
+    }
+}
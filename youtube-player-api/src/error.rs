@@ -0,0 +1,205 @@
+use alloc::string::ToString;
+use core::fmt;
+use wasm_bindgen::JsValue;
+
+/// Reasons why the Youtube IFrame API couldn't be loaded or used.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApiLoadError {
+    /// Global `window` or `document` object isn't available, e.g. inside a web worker.
+    NoWindow,
+    /// API script tag couldn't be added to the page.
+    ScriptInjectionFailed,
+    /// Global `onYouTubeIframeAPIReady` handler couldn't be replaced.
+    ReadyHandlerFailed,
+    /// Global `YT` object or its `Player` constructor is missing.
+    YtGlobalMissing,
+    /// Constructor `YT.Player` threw an error.
+    PlayerConstructFailed,
+    /// API wasn't ready within the time set by `LoaderOptions::timeout`.
+    Timeout,
+}
+
+impl ApiLoadError {
+    const ALL: [Self; 6] = [
+        Self::NoWindow,
+        Self::ScriptInjectionFailed,
+        Self::ReadyHandlerFailed,
+        Self::YtGlobalMissing,
+        Self::PlayerConstructFailed,
+        Self::Timeout,
+    ];
+
+    /// Code of the error as stored in the loader state on `window`.
+    pub(crate) fn code(self) -> u8 {
+        self as u8
+    }
+
+    pub(crate) fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(code)).copied()
+    }
+}
+
+impl fmt::Display for ApiLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoWindow => f.write_str("Global window or document object isn't available!"),
+            Self::ScriptInjectionFailed => f.write_str("Youtube API script couldn't be added!"),
+            Self::ReadyHandlerFailed => {
+                f.write_str("Youtube API ready handler (onYouTubeIframeAPIReady) couldn't be set!")
+            }
+            Self::YtGlobalMissing => f.write_str("Global YT object isn't loaded!"),
+            Self::PlayerConstructFailed => f.write_str("Youtube player couldn't be constructed!"),
+            Self::Timeout => f.write_str("Loading Youtube API timed out!"),
+        }
+    }
+}
+
+/// Reasons why a player couldn't be created.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlayerInitError {
+    /// Youtube IFrame API couldn't be loaded.
+    ApiLoad(ApiLoadError),
+    /// Youtube API couldn't bind a player to the target element.
+    PlayerBinding,
+    /// Registry already contains a player for the target element.
+    ElementInUse,
+    /// Container element for the player couldn't be created.
+    ContainerCreation,
+}
+
+impl fmt::Display for PlayerInitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApiLoad(error) => error.fmt(f),
+            Self::PlayerBinding => f.write_str("Player couldn't be bound to the target element!"),
+            Self::ElementInUse => f.write_str("Target element already has a player!"),
+            Self::ContainerCreation => f.write_str("Player container couldn't be created!"),
+        }
+    }
+}
+
+/// Reasons why a player command was rejected.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandError {
+    /// Player isn't ready yet and its `PendingCommandPolicy` is `Error`.
+    NotReady,
+    /// Player couldn't be constructed or was already destroyed.
+    Unavailable,
+    /// Request couldn't be converted from JS or into the arguments of the Youtube API.
+    InvalidRequest,
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotReady => f.write_str("Player isn't ready yet, command rejected!"),
+            Self::Unavailable => f.write_str("Player isn't available, command rejected!"),
+            Self::InvalidRequest => {
+                f.write_str("Request couldn't be converted for the Youtube API!")
+            }
+        }
+    }
+}
+
+/// Reasons why a text couldn't be parsed as `VideoRef`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VideoRefError {
+    /// Text is neither a video ID nor a supported Youtube link.
+    UnsupportedUrl,
+    /// Video ID doesn't consist of 11 characters `A-Z`, `a-z`, `0-9`, `-` and `_`.
+    InvalidVideoId,
+    /// Parameter `list` or `index` is malformed.
+    InvalidPlaylist,
+    /// Parameter `t` or `start` is malformed.
+    InvalidStartTime,
+    /// Link references neither a video nor a playlist.
+    MissingVideo,
+}
+
+impl fmt::Display for VideoRefError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedUrl => f.write_str("Not a Youtube video ID or link!"),
+            Self::InvalidVideoId => f.write_str("Invalid Youtube video ID!"),
+            Self::InvalidPlaylist => f.write_str("Invalid playlist ID or index!"),
+            Self::InvalidStartTime => f.write_str("Invalid start time!"),
+            Self::MissingVideo => f.write_str("Link doesn't contain a video or playlist!"),
+        }
+    }
+}
+
+impl From<ApiLoadError> for PlayerInitError {
+    fn from(error: ApiLoadError) -> Self {
+        Self::ApiLoad(error)
+    }
+}
+
+// reject JS promises with a proper error object
+impl From<ApiLoadError> for JsValue {
+    fn from(error: ApiLoadError) -> Self {
+        js_sys::Error::new(&error.to_string()).into()
+    }
+}
+
+impl From<PlayerInitError> for JsValue {
+    fn from(error: PlayerInitError) -> Self {
+        js_sys::Error::new(&error.to_string()).into()
+    }
+}
+
+// rejected commands throw in JS
+impl From<CommandError> for JsValue {
+    fn from(error: CommandError) -> Self {
+        js_sys::Error::new(&error.to_string()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_load_error_code() {
+        for error in ApiLoadError::ALL {
+            assert_eq!(Some(error), ApiLoadError::from_code(error.code()));
+        }
+
+        assert_eq!(None, ApiLoadError::from_code(6));
+    }
+
+    #[test]
+    fn player_init_error_display() {
+        assert_eq!(
+            "Global YT object isn't loaded!",
+            PlayerInitError::from(ApiLoadError::YtGlobalMissing).to_string()
+        );
+        assert_eq!(
+            "Player couldn't be bound to the target element!",
+            PlayerInitError::PlayerBinding.to_string()
+        );
+        assert_eq!(
+            "Target element already has a player!",
+            PlayerInitError::ElementInUse.to_string()
+        );
+    }
+
+    #[test]
+    fn command_error_display() {
+        assert_eq!(
+            "Player isn't ready yet, command rejected!",
+            CommandError::NotReady.to_string()
+        );
+        assert_eq!(
+            "Player isn't available, command rejected!",
+            CommandError::Unavailable.to_string()
+        );
+        assert_eq!(
+            "Request couldn't be converted for the Youtube API!",
+            CommandError::InvalidRequest.to_string()
+        );
+    }
+}
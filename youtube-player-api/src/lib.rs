@@ -5,37 +5,203 @@
 // extern crate test;
 
 extern crate alloc;
-
+// derived `TypeScript` implementations refer to the crate by name
+extern crate self as youtube_player_api;
+
+mod api_load;
+mod error;
+mod loader_options;
+mod registry;
+mod signal;
+mod typings;
+mod video_ref;
 mod wrapper;
 
-use alloc::{boxed::Box, rc::Rc};
-use core::cell::RefCell;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+
+use alloc::{boxed::Box, string::String};
 
 use js_sys::{try_iter, Array, Function, Object, Promise, Reflect};
 use wasm_bindgen::{prelude::*, JsCast};
-use web_sys::{window, Element};
+use wasm_bindgen_futures::future_to_promise;
+use web_sys::{console, window, Document, Element, Node};
+
+use api_load::ApiLoad;
+use wrapper::LoaderOptionsObject;
 
-pub use wrapper::{PlayerEvents, PlayerOptions, PlayerState, PlayerVars, YtPlayer};
+pub use error::{ApiLoadError, CommandError, PlayerInitError, VideoRefError};
+pub use loader_options::{LoaderOptions, ScriptTarget};
+pub use registry::PlayerRegistry;
+pub use typings::TypeScript;
+pub use video_ref::VideoRef;
+
+pub use wrapper::{
+    IvLoadPolicy, ListType, MonitorOptions, PendingCommandPolicy, PlaybackQuality, PlayerError,
+    PlayerEvent, PlayerEventName, PlayerEvents, PlayerOptions, PlayerSnapshot, PlayerState,
+    PlayerTarget, PlayerVars, PlaylistRequest, PlaylistSource, Seek, Stall, VideoRequest,
+    VideoSource, Volume, VolumeChange, YtPlayer,
+};
 
 #[cfg(feature = "wee_alloc")]
 // Use `wee_alloc` as the global allocator.
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc<'_> = wee_alloc::WeeAlloc::INIT;
 
+/// Property of `window` holding the loader state with members `options` and `load`.
+///
+/// State lives on the JS side like the `YT` global, a Rust static would have to be `Sync`.
+const LOADER_STATE_PROPERTY: &str = "__youtubePlayerApiLoader";
+
+/// Loader state object, created on first use, `None` without `window`.
+fn loader_state() -> Option<Object> {
+    let window = window()?;
+    let state = Reflect::get(&window, &LOADER_STATE_PROPERTY.into()).ok()?;
+
+    if let Ok(state) = state.dyn_into::<Object>() {
+        return Some(state);
+    }
+
+    let state = Object::new();
+    Reflect::set(&window, &LOADER_STATE_PROPERTY.into(), &state).ok()?;
+
+    Some(state)
+}
+
+fn loader_state_member(member: &str) -> Option<JsValue> {
+    loader_state()
+        .and_then(|state| Reflect::get(&state, &member.into()).ok())
+        .filter(|value| !value.is_undefined())
+}
+
+fn set_loader_state_member(member: &str, value: &JsValue) {
+    if let Some(state) = loader_state() {
+        let _success = Reflect::set(&state, &member.into(), value);
+    }
+}
+
 #[wasm_bindgen(js_name = initYtApi)]
 pub fn init_yt_api() -> Promise {
+    let api_loaded = load_yt_api();
+
+    future_to_promise(async move {
+        api_loaded.wait().await?;
+
+        // resolve with YT global object, like previous versions did
+        Ok(get_yt_global()?.into())
+    })
+}
+
+#[wasm_bindgen(js_name = initYtApiWithOptions)]
+pub fn js_init_yt_api_with_options(options: LoaderOptionsObject) -> Result<Promise, JsValue> {
+    let options = LoaderOptions::try_from(JsValue::from(options))?;
+
+    Ok(init_yt_api_with_options(options))
+}
+
+/// Load Youtube API with custom options, also used by all players created afterwards.
+///
+/// Loader options don't have any effect if the API is already loading or loaded.
+pub fn init_yt_api_with_options(options: LoaderOptions) -> Promise {
+    match serde_wasm_bindgen::to_value(&options) {
+        Ok(options) => set_loader_state_member("options", &options),
+        Err(error) => console::error_1(&error.into()),
+    }
+
+    init_yt_api()
+}
+
+fn loader_options() -> LoaderOptions {
+    loader_state_member("options")
+        .and_then(|options| LoaderOptions::try_from(options).ok())
+        .unwrap_or_default()
+}
+
+/// Host for new players as configured by `LoaderOptions::host`.
+pub(crate) fn loader_host() -> Option<String> {
+    loader_options().host
+}
+
+/// Start loading the Youtube API, or return the load already in progress.
+///
+/// The load is shared by all callers, so they await it instead of chaining ready handlers.
+/// Failed loads and loaded APIs which were removed from the page again are started anew.
+pub(crate) fn load_yt_api() -> ApiLoad {
+    let current_load = loader_state_member("load").and_then(ApiLoad::from_js);
+
+    if let Some(current_load) = current_load {
+        let is_reusable = match current_load.value() {
+            // still loading
+            None => true,
+            Some(Ok(())) => get_yt_global().is_ok(),
+            Some(Err(_)) => false,
+        };
+
+        if is_reusable {
+            return current_load;
+        }
+    }
+
+    let api_loaded = start_yt_api_load();
+    set_loader_state_member("load", api_loaded.as_js());
+
+    api_loaded
+}
+
+fn start_yt_api_load() -> ApiLoad {
     #[cfg(feature = "std")]
     // enable feature "std" to show rust stack trace instead of cryptic "RuntimeError: unreachable executed"
     std::panic::set_hook(Box::new(console_error_panic_hook::hook));
 
-    let window = window().unwrap();
-    let document = window.document().unwrap();
+    // resolved when library has successfully initialized youtube player api
+    let api_loaded = ApiLoad::new();
+    let options = loader_options();
+
+    if let Err(error) = install_yt_api(&api_loaded, &options) {
+        api_loaded.resolve(Err(error));
+    }
+
+    if let Some(timeout) = options.timeout {
+        start_load_timeout(&api_loaded, timeout);
+    }
+
+    api_loaded
+}
 
-    // create promise to signal when library has successfully initialized youtube player api
-    let (api_ready, init_resolver, _) = controllable_promise();
+fn start_load_timeout(api_loaded: &ApiLoad, timeout: u32) {
+    let timeout_signal = api_loaded.clone();
+
+    // signal keeps its first value, so a timeout after successful loading is ignored
+    let on_timeout = Closure::once_into_js(move || {
+        timeout_signal.resolve(Err(ApiLoadError::Timeout));
+    });
+
+    let timeout_set = window().map(|window| {
+        window.set_timeout_with_callback_and_timeout_and_arguments_0(
+            on_timeout.unchecked_ref(),
+            timeout.try_into().unwrap_or(i32::MAX),
+        )
+    });
+
+    if let Some(Err(error)) = timeout_set {
+        console::error_1(&error);
+    }
+}
+
+fn install_yt_api(api_loaded: &ApiLoad, options: &LoaderOptions) -> Result<(), ApiLoadError> {
+    let window = window().ok_or(ApiLoadError::NoWindow)?;
+    let document = window.document().ok_or(ApiLoadError::NoWindow)?;
+
+    // skip loading API if already loaded, also if loaded by other means than the script tag
+    if get_yt_global().is_ok() {
+        // signal api loading complete
+        api_loaded.resolve(Ok(()));
+
+        return Ok(());
+    }
 
     // check if api script was already added
-    let api_script_url = "https://www.youtube.com/player_api";
+    let api_script_url = options.script_url.as_str();
     let mut script_exists = false;
 
     let script_tags = document.get_elements_by_tag_name("script");
@@ -56,119 +222,119 @@ pub fn init_yt_api() -> Promise {
         }
     }
 
-    // skip loading API if already loaded
-    if script_exists {
-        let yt_global = get_yt_global();
-
-        // api ready handler already ran before
-        if let Ok(yt_global) = yt_global {
-            // signal api loading complete
-            init_resolver
-                .borrow()
-                .as_ref()
-                .unwrap()
-                .apply(&JsValue::null(), &Array::from_iter([yt_global]))
-                .unwrap();
-
-            return api_ready;
-        }
-    }
-
     // check and save if there's already a ready handler function
-    let previous_ready_function = Reflect::get(&window, &"onYouTubeIframeAPIReady".into()).unwrap();
+    let previous_ready_function = Reflect::get(&window, &"onYouTubeIframeAPIReady".into())
+        .map_err(|_| ApiLoadError::ReadyHandlerFailed)?;
 
     // create ready handler function specific for wrapper library
     // can also run again if script already exists but YT global object hasn't fully laoded yet
+    let init_signal = api_loaded.clone();
+
     let new_handler = Closure::wrap(Box::new(move || {
         // execute custom code for library
-        let yt_global = get_yt_global().unwrap();
+        let api_state = get_yt_global().map(|_yt_global| ());
 
         // signal api loading complete
-        init_resolver
-            .borrow()
-            .as_ref()
-            .unwrap()
-            .apply(&JsValue::null(), &Array::from_iter([yt_global]))
-            .unwrap();
+        init_signal.resolve(api_state);
 
         // call and restore previous ready handler function
         // after everything is done
         if let Some(ready_fn_to_restore) = previous_ready_function.dyn_ref::<Function>() {
             // call original ready handler function to prevent other scripts from breaking
-            ready_fn_to_restore
-                .apply(&JsValue::null(), &Array::new())
-                .unwrap();
-
-            let _success = Reflect::set(
-                &web_sys::window().unwrap(),
-                &"onYouTubeIframeAPIReady".into(),
-                ready_fn_to_restore.as_ref(),
-            )
-            .unwrap();
+            if let Err(error) = ready_fn_to_restore.apply(&JsValue::null(), &Array::new()) {
+                console::error_1(&error);
+            }
+
+            if let Some(window) = web_sys::window() {
+                let _success = Reflect::set(
+                    &window,
+                    &"onYouTubeIframeAPIReady".into(),
+                    ready_fn_to_restore.as_ref(),
+                );
+            }
         }
     }) as Box<dyn FnMut()>);
 
     // put library ready handler in place, will restore the original ready handler if needed
     // multiple nested handlers should restore each other
-    let _success = Reflect::set(
+    let handler_set = Reflect::set(
         &window,
         &"onYouTubeIframeAPIReady".into(),
         &new_handler.into_js_value(),
-    )
-    .unwrap();
+    );
+
+    if !matches!(handler_set, Ok(true)) {
+        return Err(ApiLoadError::ReadyHandlerFailed);
+    }
 
     // only add api script once
     if !script_exists {
         // load the IFrame Player API code asynchronously
-        let document = window.document().unwrap();
-
-        let api_script = document.create_element("script").unwrap();
-        api_script.set_attribute("src", api_script_url).unwrap();
-
-        let first_script_tag = document.get_elements_by_tag_name("script").item(0).unwrap();
-        first_script_tag
-            .parent_node()
-            .unwrap()
-            .insert_before(&api_script, Some(&first_script_tag))
-            .unwrap();
+        inject_api_script(&document, options)?;
     }
 
-    api_ready
+    Ok(())
 }
 
-type PromiseConstructorFunction = Rc<RefCell<Option<Function>>>;
+fn inject_api_script(document: &Document, options: &LoaderOptions) -> Result<(), ApiLoadError> {
+    let api_script = document
+        .create_element("script")
+        .map_err(|_| ApiLoadError::ScriptInjectionFailed)?;
 
-fn controllable_promise() -> (
-    Promise,
-    PromiseConstructorFunction,
-    PromiseConstructorFunction,
-) {
-    let resolve_function: PromiseConstructorFunction = Rc::new(RefCell::new(None));
-    let reject_function: PromiseConstructorFunction = Rc::new(RefCell::new(None));
+    api_script
+        .set_attribute("src", &options.script_url)
+        .map_err(|_| ApiLoadError::ScriptInjectionFailed)?;
 
-    let promise_resolve = resolve_function.clone();
-    let promise_reject = reject_function.clone();
+    if let Some(nonce) = &options.nonce {
+        api_script
+            .set_attribute("nonce", nonce)
+            .map_err(|_| ApiLoadError::ScriptInjectionFailed)?;
+    }
 
-    let promise = Promise::new(&mut move |resolve, reject| {
-        promise_resolve.replace(Some(resolve));
-        promise_reject.replace(Some(reject));
-    });
+    let first_script_tag = document.get_elements_by_tag_name("script").item(0);
+
+    let inserted = match (&options.insertion_target, first_script_tag) {
+        (ScriptTarget::BeforeFirstScript, Some(first_script_tag)) => first_script_tag
+            .parent_node()
+            .ok_or(ApiLoadError::ScriptInjectionFailed)?
+            .insert_before(&api_script, Some(&first_script_tag)),
+        // pages without any script tag get the API script in their head
+        (ScriptTarget::BeforeFirstScript, None) | (ScriptTarget::Head, _) => {
+            append_script(document.head().map(Node::from), &api_script)
+        }
+        (ScriptTarget::Body, _) => append_script(document.body().map(Node::from), &api_script),
+        (ScriptTarget::Element(element_id), _) => append_script(
+            document.get_element_by_id(element_id).map(Node::from),
+            &api_script,
+        ),
+    };
 
-    (promise, resolve_function, reject_function)
+    inserted.map_err(|_| ApiLoadError::ScriptInjectionFailed)?;
+
+    Ok(())
+}
+
+fn append_script(parent: Option<Node>, api_script: &Element) -> Result<Node, JsValue> {
+    match parent {
+        Some(parent) => parent.append_child(api_script),
+        None => Err(JsValue::undefined()),
+    }
 }
 
-fn get_yt_global() -> Result<Object, JsValue> {
-    let window = window().unwrap();
+pub(crate) fn get_yt_global() -> Result<Object, ApiLoadError> {
+    let window = window().ok_or(ApiLoadError::NoWindow)?;
 
-    let yt_global = Reflect::get(&window, &"YT".into())?;
+    let yt_global =
+        Reflect::get(&window, &"YT".into()).map_err(|_| ApiLoadError::YtGlobalMissing)?;
 
     if let Ok(yt_global_object) = yt_global.dyn_into::<Object>() {
-        let player_constructor = Reflect::get(&yt_global_object, &"Player".into())?;
+        let player_constructor = Reflect::get(&yt_global_object, &"Player".into())
+            .map_err(|_| ApiLoadError::YtGlobalMissing)?;
 
         if player_constructor.dyn_into::<Function>().is_ok() {
             return Ok(yt_global_object);
         }
     }
 
-    Err(JsValue::undefined())
+    Err(ApiLoadError::YtGlobalMissing)
 }
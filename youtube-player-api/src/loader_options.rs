@@ -0,0 +1,138 @@
+use alloc::{borrow::ToOwned, string::String};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::TypeScript;
+
+/// Place where the API script tag gets inserted.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, TypeScript)]
+#[serde(rename_all = "camelCase")]
+pub enum ScriptTarget {
+    /// Insert before the first script tag of the page, append to `<head>` if there's none.
+    #[default]
+    BeforeFirstScript,
+    Head,
+    Body,
+    /// Append to the element with the given ID.
+    Element(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TypeScript)]
+#[serde(default)]
+pub struct LoaderOptions {
+    /// URL of the IFrame API script, e.g. a self-hosted mirror or a local stub for tests.
+    #[serde(rename = "scriptUrl")]
+    pub script_url: String,
+    /// Host used by created players, e.g. `https://www.youtube-nocookie.com` for privacy-enhanced embeds.
+    pub host: Option<String>,
+    /// Nonce of the script tag, needed for pages with a strict Content Security Policy.
+    pub nonce: Option<String>,
+    #[serde(rename = "insertionTarget")]
+    pub insertion_target: ScriptTarget,
+    /// Milliseconds until loading the API fails, waits forever if not set.
+    pub timeout: Option<u32>,
+}
+
+impl LoaderOptions {
+    pub const DEFAULT_SCRIPT_URL: &'static str = "https://www.youtube.com/player_api";
+    pub const NO_COOKIE_HOST: &'static str = "https://www.youtube-nocookie.com";
+
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn script_url(mut self, script_url: String) -> Self {
+        self.script_url = script_url;
+        self
+    }
+
+    pub fn host(mut self, host: String) -> Self {
+        self.host = Some(host);
+        self
+    }
+
+    pub fn nonce(mut self, nonce: String) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    pub fn insertion_target(mut self, insertion_target: ScriptTarget) -> Self {
+        self.insertion_target = insertion_target;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+impl Default for LoaderOptions {
+    fn default() -> Self {
+        Self {
+            script_url: Self::DEFAULT_SCRIPT_URL.to_owned(),
+            host: None,
+            nonce: None,
+            insertion_target: ScriptTarget::default(),
+            timeout: None,
+        }
+    }
+}
+
+impl TryFrom<JsValue> for LoaderOptions {
+    type Error = serde_wasm_bindgen::Error;
+
+    fn try_from(options: JsValue) -> Result<Self, Self::Error> {
+        if options.is_undefined() || options.is_null() {
+            return Ok(Self::default());
+        }
+
+        serde_wasm_bindgen::from_value(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loader_options_default() {
+        let loader_options = LoaderOptions::new();
+
+        assert_eq!(LoaderOptions::DEFAULT_SCRIPT_URL, loader_options.script_url);
+        assert_eq!(None, loader_options.host);
+        assert_eq!(None, loader_options.nonce);
+        assert_eq!(
+            ScriptTarget::BeforeFirstScript,
+            loader_options.insertion_target
+        );
+        assert_eq!(None, loader_options.timeout);
+    }
+
+    #[test]
+    fn loader_options_set() {
+        let loader_options = LoaderOptions::new()
+            .script_url("http://localhost:4000/player_api.js".to_owned())
+            .host(LoaderOptions::NO_COOKIE_HOST.to_owned())
+            .nonce("r4nd0m".to_owned())
+            .insertion_target(ScriptTarget::Element("scripts".to_owned()))
+            .timeout(5000);
+
+        assert_eq!(
+            "http://localhost:4000/player_api.js",
+            loader_options.script_url
+        );
+        assert_eq!(
+            Some(LoaderOptions::NO_COOKIE_HOST.to_owned()),
+            loader_options.host
+        );
+        assert_eq!(Some("r4nd0m".to_owned()), loader_options.nonce);
+        assert_eq!(
+            ScriptTarget::Element("scripts".to_owned()),
+            loader_options.insertion_target
+        );
+        assert_eq!(Some(5000), loader_options.timeout);
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::{boxed::Box, string::String};
+
+use hashbrown::HashMap;
+
+use crate::{load_yt_api, PlayerInitError, PlayerOptions, YtPlayer};
+
+/// Players keyed by the ID of their target element, at most one player per element.
+///
+/// All players share the same API load, see `init_yt_api`.
+#[derive(Debug, Default)]
+pub struct PlayerRegistry {
+    players: HashMap<String, YtPlayer>,
+}
+
+impl PlayerRegistry {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// Create and register a player, commands are queued until it's ready (see `PendingCommandPolicy`).
+    ///
+    /// The Youtube API has to be loaded already, otherwise use `create_async`.
+    pub fn create(
+        &mut self,
+        player_id: &str,
+        options: PlayerOptions,
+    ) -> Result<&YtPlayer, PlayerInitError> {
+        if self.contains(player_id) {
+            return Err(PlayerInitError::ElementInUse);
+        }
+
+        let player = YtPlayer::new(player_id, options.into());
+
+        // a broken player isn't registered, so the element stays available
+        if let Some(error) = player.init_error() {
+            return Err(error);
+        }
+
+        Ok(self.insert_unchecked(player))
+    }
+
+    /// Load the Youtube API if necessary, then create and register a player like `create`.
+    ///
+    /// Concurrent callers await the same API load.
+    pub async fn create_async(
+        &mut self,
+        player_id: &str,
+        options: PlayerOptions,
+    ) -> Result<&YtPlayer, PlayerInitError> {
+        if self.contains(player_id) {
+            return Err(PlayerInitError::ElementInUse);
+        }
+
+        load_yt_api().wait().await?;
+
+        self.create(player_id, options)
+    }
+
+    /// Register a player created elsewhere, e.g. by `YtPlayer::create_async`.
+    ///
+    /// A player rejected because its element already has a registered player is returned
+    /// with the error, so it isn't destroyed by a failed insert.
+    pub fn insert(
+        &mut self,
+        player: YtPlayer,
+    ) -> Result<&YtPlayer, (PlayerInitError, Box<YtPlayer>)> {
+        if self.contains(&player.id()) {
+            return Err((PlayerInitError::ElementInUse, Box::new(player)));
+        }
+
+        Ok(self.insert_unchecked(player))
+    }
+
+    fn insert_unchecked(&mut self, player: YtPlayer) -> &YtPlayer {
+        self.players.entry(player.id()).or_insert(player)
+    }
+
+    pub fn get(&self, player_id: &str) -> Option<&YtPlayer> {
+        self.players.get(player_id)
+    }
+
+    pub fn get_mut(&mut self, player_id: &str) -> Option<&mut YtPlayer> {
+        self.players.get_mut(player_id)
+    }
+
+    pub fn contains(&self, player_id: &str) -> bool {
+        self.players.contains_key(player_id)
+    }
+
+    /// Unregister a player without destroying it.
+    pub fn remove(&mut self, player_id: &str) -> Option<YtPlayer> {
+        self.players.remove(player_id)
+    }
+
+    /// Destroy and unregister a player, returns `false` if there's no player for the element.
+    pub fn destroy(&mut self, player_id: &str) -> bool {
+        match self.players.remove(player_id) {
+            Some(mut player) => {
+                player.destroy();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Pause all ready players, pending players are skipped instead of queueing a pause.
+    pub fn pause_all(&self) {
+        // skip players which aren't ready yet, a queued pause would stop them right after autoplay
+        for player in self.players.values().filter(|player| player.is_ready()) {
+            let _result = player.pause_video();
+        }
+    }
+
+    pub fn destroy_all(&mut self) {
+        for (_player_id, mut player) in self.players.drain() {
+            player.destroy();
+        }
+    }
+
+    /// Element IDs and players in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &YtPlayer)> {
+        self.players
+            .iter()
+            .map(|(player_id, player)| (player_id.as_str(), player))
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.players.keys().map(|player_id| player_id.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+}
@@ -0,0 +1,133 @@
+use alloc::{rc::Rc, vec::Vec};
+use core::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+#[derive(Debug)]
+struct SignalState<T> {
+    value: Option<T>,
+    wakers: Vec<Waker>,
+}
+
+/// Single-threaded signal, resolved once and awaitable by any number of futures.
+/// Replaces JS promises, so rust code can await the loading state without `JsValue` round-trips.
+#[derive(Debug)]
+pub(crate) struct Signal<T> {
+    state: Rc<RefCell<SignalState<T>>>,
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T: Clone> Signal<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Rc::new(RefCell::new(SignalState {
+                value: None,
+                wakers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Resolve signal and wake all waiting futures, only the first value is kept.
+    pub(crate) fn resolve(&self, value: T) {
+        let wakers = {
+            let mut state = self.state.borrow_mut();
+
+            if state.value.is_some() {
+                return;
+            }
+
+            state.value = Some(value);
+            core::mem::take(&mut state.wakers)
+        };
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Value of a resolved signal, `None` while still pending.
+    pub(crate) fn value(&self) -> Option<T> {
+        self.state.borrow().value.clone()
+    }
+
+    pub(crate) fn wait(&self) -> SignalFuture<T> {
+        SignalFuture {
+            signal: self.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SignalFuture<T> {
+    signal: Signal<T>,
+}
+
+impl<T: Clone> Future for SignalFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.signal.state.borrow_mut();
+
+        match &state.value {
+            Some(value) => Poll::Ready(value.clone()),
+            None => {
+                state.wakers.push(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{sync::Arc, task::Wake};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn signal_resolve() {
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let signal = Signal::new();
+        assert_eq!(None, signal.value());
+
+        let mut first = signal.wait();
+        let mut second = signal.clone().wait();
+
+        assert_eq!(Poll::Pending, Pin::new(&mut first).poll(&mut cx));
+        assert_eq!(Poll::Pending, Pin::new(&mut second).poll(&mut cx));
+
+        signal.resolve(42);
+        assert_eq!(Some(42), signal.value());
+
+        assert_eq!(2, counter.0.load(Ordering::SeqCst));
+        assert_eq!(Poll::Ready(42), Pin::new(&mut first).poll(&mut cx));
+        assert_eq!(Poll::Ready(42), Pin::new(&mut second).poll(&mut cx));
+
+        // keep first value
+        signal.resolve(0);
+
+        assert_eq!(Poll::Ready(42), Pin::new(&mut signal.wait()).poll(&mut cx));
+    }
+}
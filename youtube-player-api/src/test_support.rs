@@ -0,0 +1,194 @@
+//! Scriptable fake of the Youtube IFrame API for headless `wasm-bindgen-test` suites.
+//!
+//! Enable feature `test-support` and call `MockYt::install()` before creating players.
+//! No network access is needed, events like `onReady` are fired by the test itself.
+
+use alloc::{string::String, vec::Vec};
+
+use js_sys::{Array, Object};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(inline_js = r#"
+const API_METHODS = [
+  'playVideo', 'pauseVideo', 'stopVideo', 'seekTo', 'destroy',
+  'cueVideoById', 'loadVideoById', 'cueVideoByUrl', 'loadVideoByUrl',
+  'cuePlaylist', 'loadPlaylist', 'nextVideo', 'previousVideo', 'playVideoAt', 'setShuffle', 'setLoop',
+  'getPlaylist', 'getPlaylistIndex',
+  'getPlayerState', 'getCurrentTime', 'getDuration', 'getVideoLoadedFraction',
+  'setVolume', 'getVolume', 'mute', 'unMute', 'isMuted',
+  'setPlaybackRate', 'getPlaybackRate', 'getAvailablePlaybackRates',
+  'setPlaybackQuality', 'getPlaybackQuality', 'getAvailableQualityLevels',
+  'setSize', 'getIframe', 'getVideoData',
+];
+
+class MockPlayer {
+  constructor(target, options) {
+    // member checked by the wrapper for a successful binding
+    this.i = {};
+    this.target = target;
+    this.elementId = typeof target === 'string' ? target : target.id;
+    this.options = options || {};
+    this.calls = [];
+    this.returns = {};
+    this.listeners = {};
+    MockPlayer.players.push(this);
+  }
+
+  addEventListener(event, listener) {
+    (this.listeners[event] = this.listeners[event] || []).push(listener);
+  }
+
+  fire(eventName, data) {
+    const handlerName = 'on' + eventName[0].toUpperCase() + eventName.slice(1);
+    const listeners = [];
+    const events = this.options.events || {};
+
+    if (typeof events[handlerName] === 'function') {
+      listeners.push(events[handlerName]);
+    }
+
+    listeners.push(...(this.listeners[handlerName] || []));
+
+    for (const listener of listeners) {
+      const event = { target: this };
+
+      if (data !== undefined) {
+        event.data = data;
+      }
+
+      listener(event);
+    }
+  }
+}
+
+MockPlayer.players = [];
+
+for (const method of API_METHODS) {
+  MockPlayer.prototype[method] = function (...args) {
+    this.calls.push([method, ...args]);
+    return this.returns[method];
+  };
+}
+
+export function install_mock_yt() {
+  MockPlayer.players = [];
+  window.YT = { Player: MockPlayer, loaded: 1 };
+}
+
+export function uninstall_mock_yt() {
+  delete window.YT;
+}
+
+export function mock_players() {
+  return window.YT ? MockPlayer.players.slice() : [];
+}
+
+export function fire_api_ready() {
+  install_mock_yt();
+
+  if (typeof window.onYouTubeIframeAPIReady === 'function') {
+    window.onYouTubeIframeAPIReady();
+  }
+}
+"#)]
+extern "C" {
+    /// Fake `YT.Player` instance, recording all API method calls.
+    #[derive(Clone, Debug)]
+    pub type MockPlayer;
+
+    fn install_mock_yt();
+
+    fn uninstall_mock_yt();
+
+    fn mock_players() -> Array;
+
+    fn fire_api_ready();
+
+    #[wasm_bindgen(method, getter = elementId)]
+    pub fn element_id(this: &MockPlayer) -> String;
+
+    /// Element or element ID passed to the `YT.Player` constructor.
+    #[wasm_bindgen(method, getter)]
+    pub fn target(this: &MockPlayer) -> JsValue;
+
+    /// Options passed to the `YT.Player` constructor.
+    #[wasm_bindgen(method, getter)]
+    pub fn options(this: &MockPlayer) -> Object;
+
+    /// Recorded calls, each one an array of method name followed by its arguments.
+    #[wasm_bindgen(method, getter)]
+    pub fn calls(this: &MockPlayer) -> Array;
+
+    #[wasm_bindgen(method, getter)]
+    fn returns(this: &MockPlayer) -> Object;
+
+    /// Fire an API event like `ready` or `stateChange`, `data` is omitted if `undefined`.
+    #[wasm_bindgen(method)]
+    pub fn fire(this: &MockPlayer, event_name: &str, data: JsValue);
+}
+
+impl MockPlayer {
+    /// Names of all recorded calls in order.
+    pub fn call_names(&self) -> Vec<String> {
+        self.calls()
+            .iter()
+            .filter_map(|call| Array::from(&call).get(0).as_string())
+            .collect()
+    }
+
+    /// Arguments of the last call of the given method.
+    pub fn last_call_args(&self, method: &str) -> Option<Array> {
+        self.calls()
+            .iter()
+            .map(|call| Array::from(&call))
+            .rfind(|call| call.get(0).as_string().as_deref() == Some(method))
+            .map(|call| call.slice(1, call.length()))
+    }
+
+    /// Let a getter like `getPlayerState` return the given value.
+    pub fn set_return(&self, method: &str, value: JsValue) {
+        let _success = js_sys::Reflect::set(&self.returns(), &method.into(), &value);
+    }
+
+    pub fn fire_ready(&self) {
+        self.fire("ready", JsValue::undefined());
+    }
+}
+
+/// Installed fake `window.YT` global, removed again on drop.
+#[derive(Debug)]
+pub struct MockYt {
+    _private: (),
+}
+
+impl MockYt {
+    /// Install fake `window.YT` global, so the API counts as already loaded.
+    pub fn install() -> Self {
+        install_mock_yt();
+
+        Self { _private: () }
+    }
+
+    /// Install fake `window.YT` global and call `onYouTubeIframeAPIReady`,
+    /// like the real API script does after it has been loaded.
+    pub fn install_with_ready_handler() -> Self {
+        fire_api_ready();
+
+        Self { _private: () }
+    }
+
+    /// All players constructed since the mock was installed.
+    pub fn players(&self) -> Vec<MockPlayer> {
+        mock_players().iter().map(JsCast::unchecked_into).collect()
+    }
+
+    pub fn last_player(&self) -> Option<MockPlayer> {
+        self.players().pop()
+    }
+}
+
+impl Drop for MockYt {
+    fn drop(&mut self) {
+        uninstall_mock_yt();
+    }
+}
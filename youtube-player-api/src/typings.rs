@@ -0,0 +1,181 @@
+pub use youtube_player_api_derive::TypeScript;
+
+/// Type with a TypeScript declaration, which is emitted into the `.d.ts` file by `wasm-bindgen`.
+///
+/// Implemented with `#[derive(TypeScript)]`, which follows the `serde` attributes of the type.
+pub trait TypeScript {
+    /// Name of the declared type.
+    const NAME: &'static str;
+    /// Declaration as emitted into the `.d.ts` file.
+    const DECLARATION: &'static str;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, vec};
+
+    use super::*;
+    use crate::{
+        IvLoadPolicy, ListType, LoaderOptions, MonitorOptions, PendingCommandPolicy,
+        PlaybackQuality, PlayerOptions, PlayerSnapshot, PlayerState, PlayerVars, PlaylistRequest,
+        PlaylistSource, ScriptTarget, Seek, Stall, VideoRequest, VideoSource, Volume, VolumeChange,
+    };
+
+    fn full_player_vars() -> PlayerVars {
+        PlayerVars::new()
+            .autoplay(true)
+            .cc_lang_pref("en".to_owned())
+            .cc_load_policy(false)
+            .controls(true)
+            .disable_kb(true)
+            .enable_js_api(true)
+            .end(120)
+            .fullscreen(false)
+            .interface_language("de".to_owned())
+            .iv_load_policy(IvLoadPolicy::Hide)
+            .loop_playback(true)
+            .mute(true)
+            .origin("https://example.com".to_owned())
+            .playlist(vec!["M7lc1UVf-VE".to_owned(), "bS4Q-WWyl3Q".to_owned()])
+            .plays_inline(true)
+            .rel(false)
+            .start(30)
+            .widget_referrer("https://example.com".to_owned())
+    }
+
+    #[test]
+    fn player_options_typings() {
+        assert_eq!(
+            "export interface PlayerOptions {\n  videoId?: string;\n  host?: string;\n  width?: number;\n  height?: number;\n  playerVars?: PlayerVars;\n  pendingCommands?: PendingCommandPolicy;\n  timeUpdateInterval?: number;\n  playbackMonitor?: MonitorOptions;\n  aspectRatio?: number;\n}",
+            PlayerOptions::DECLARATION
+        );
+        assert_eq!(
+            "export interface PlayerVars {\n  autoplay?: 0 | 1;\n  cc_lang_pref?: string;\n  cc_load_policy?: 0 | 1;\n  controls?: 0 | 1;\n  disablekb?: 0 | 1;\n  enablejsapi?: 0 | 1;\n  end?: number;\n  fs?: 0 | 1;\n  hl?: string;\n  iv_load_policy?: IvLoadPolicy;\n  loop?: 0 | 1;\n  mute?: 0 | 1;\n  origin?: string;\n  playlist?: string;\n  playsinline?: 0 | 1;\n  rel?: 0 | 1;\n  start?: number;\n  widget_referrer?: string;\n}",
+            PlayerVars::DECLARATION
+        );
+        assert_eq!(
+            "export interface MonitorOptions {\n  interval?: number;\n  seekThreshold?: number;\n  stallThreshold?: number;\n}",
+            MonitorOptions::DECLARATION
+        );
+
+        // members with an explicit TypeScript type are serialized as declared
+        assert_eq!(
+            serde_json::json!({
+                "autoplay": 1,
+                "cc_lang_pref": "en",
+                "cc_load_policy": 0,
+                "controls": 1,
+                "disablekb": 1,
+                "enablejsapi": 1,
+                "end": 120,
+                "fs": 0,
+                "hl": "de",
+                "iv_load_policy": 3,
+                "loop": 1,
+                "mute": 1,
+                "origin": "https://example.com",
+                "playlist": "M7lc1UVf-VE,bS4Q-WWyl3Q",
+                "playsinline": 1,
+                "rel": 0,
+                "start": 30,
+                "widget_referrer": "https://example.com",
+            }),
+            serde_json::to_value(full_player_vars()).unwrap()
+        );
+    }
+
+    #[test]
+    fn loader_options_typings() {
+        assert_eq!(
+            "export interface LoaderOptions {\n  scriptUrl?: string;\n  host?: string;\n  nonce?: string;\n  insertionTarget?: ScriptTarget;\n  timeout?: number;\n}",
+            LoaderOptions::DECLARATION
+        );
+        assert_eq!(
+            "export type ScriptTarget = 'beforeFirstScript' | 'head' | 'body' | { element: string };",
+            ScriptTarget::DECLARATION
+        );
+    }
+
+    #[test]
+    fn enum_typings() {
+        assert_eq!(
+            "export const enum PlayerState {\n  UNSTARTED = -1,\n  ENDED = 0,\n  PLAYING = 1,\n  PAUSED = 2,\n  BUFFERING = 3,\n  CUED = 5,\n}",
+            PlayerState::DECLARATION
+        );
+        assert_eq!(
+            "export type PendingCommandPolicy = 'drop' | 'queue' | 'error';",
+            PendingCommandPolicy::DECLARATION
+        );
+        assert_eq!(
+            "export const enum IvLoadPolicy {\n  SHOW = 1,\n  HIDE = 3,\n}",
+            IvLoadPolicy::DECLARATION
+        );
+        assert_eq!(
+            "export type ListType = 'playlist' | 'user_uploads';",
+            ListType::DECLARATION
+        );
+
+        // values of const enums are set by hand, they have to match the serialized values
+        for state in PlayerState::KNOWN {
+            assert_eq!(
+                serde_json::json!(state.value()),
+                serde_json::to_value(state).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn event_data_typings() {
+        assert_eq!(
+            "export interface Seek {\n  from: number;\n  to: number;\n}",
+            Seek::DECLARATION
+        );
+        assert_eq!(
+            "export interface Stall {\n  duration: number;\n}",
+            Stall::DECLARATION
+        );
+        assert_eq!("export type Volume = number;", Volume::DECLARATION);
+        assert_eq!(
+            "export interface VolumeChange {\n  volume: Volume;\n  muted: boolean;\n}",
+            VolumeChange::DECLARATION
+        );
+    }
+
+    #[test]
+    fn player_snapshot_typings() {
+        assert_eq!(
+            "export interface PlayerSnapshot {\n  videoId?: string;\n  currentTime: number;\n  state: PlayerState;\n  playbackRate: number;\n  volume: Volume;\n  muted: boolean;\n  playbackQuality: PlaybackQuality;\n  playlist?: string[];\n  playlistIndex?: number;\n}",
+            PlayerSnapshot::DECLARATION
+        );
+    }
+
+    #[test]
+    fn request_typings() {
+        assert_eq!(
+            "export type VideoSource = { videoId: string } | { mediaContentUrl: string };",
+            VideoSource::DECLARATION
+        );
+        assert_eq!(
+            "export type VideoRequest = VideoSource & {\n  startSeconds?: number;\n  endSeconds?: number;\n  suggestedQuality?: PlaybackQuality;\n};",
+            VideoRequest::DECLARATION
+        );
+        assert_eq!(
+            "export type PlaylistSource = { playlist: string[] } | { listType: ListType; list: string };",
+            PlaylistSource::DECLARATION
+        );
+        assert_eq!(
+            "export type PlaylistRequest = PlaylistSource & {\n  index?: number;\n  startSeconds?: number;\n};",
+            PlaylistRequest::DECLARATION
+        );
+        assert_eq!(
+            "export type PlaybackQuality = 'tiny' | 'small' | 'medium' | 'large' | 'hd720' | 'hd1080' | 'hd1440' | 'hd2160' | 'highres' | 'auto' | 'default';",
+            PlaybackQuality::DECLARATION
+        );
+
+        let request = VideoRequest::by_id("M7lc1UVf-VE".to_owned())
+            .suggested_quality(PlaybackQuality::Hd1440);
+        let quality = serde_json::to_value(&request).unwrap()["suggestedQuality"].clone();
+
+        assert_eq!(serde_json::json!("hd1440"), quality);
+    }
+}
@@ -0,0 +1,426 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::str::FromStr;
+
+use crate::{error::VideoRefError, PlayerOptions, PlaylistRequest, VideoRequest};
+
+const VIDEO_ID_LENGTH: usize = 11;
+
+/// Video, start time and playlist referenced by a Youtube link or a plain video ID.
+///
+/// ```
+/// use youtube_player_api::VideoRef;
+///
+/// let video_ref = VideoRef::parse("https://youtu.be/M7lc1UVf-VE?t=1m30s").unwrap();
+///
+/// assert_eq!(Some("M7lc1UVf-VE".to_owned()), video_ref.video_id);
+/// assert_eq!(Some(90), video_ref.start_seconds);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VideoRef {
+    /// Missing for links of a whole playlist, e.g. `youtube.com/playlist?list=…`.
+    pub video_id: Option<String>,
+    pub start_seconds: Option<u32>,
+    pub playlist_id: Option<String>,
+    /// Zero-based like `PlaylistRequest::index`, links count from one.
+    pub playlist_index: Option<u32>,
+}
+
+impl VideoRef {
+    /// Parse a video ID or a link to a video or playlist.
+    ///
+    /// Supported links are `youtu.be/ID`, `youtube.com/watch?v=ID`, `/shorts/ID`, `/embed/ID`,
+    /// `/live/ID`, `/v/ID` and `/playlist?list=ID`, also without scheme and on the `www.`, `m.`,
+    /// `music.` and `youtube-nocookie.com` hosts.
+    /// Start times are read from `t` (`90`, `90s`, `1m30s`, `1h2m3s`) and `start`.
+    pub fn parse(text: &str) -> Result<Self, VideoRefError> {
+        let text = text.trim();
+
+        if is_video_id(text) {
+            return Ok(Self {
+                video_id: Some(text.to_owned()),
+                ..Default::default()
+            });
+        }
+
+        let url = strip_scheme(text);
+        let host_end = url.find(['/', '?', '#']).unwrap_or(url.len());
+        let (host, rest) = url.split_at(host_end);
+        let host = host.to_ascii_lowercase();
+
+        // query and fragment are read alike, Youtube also accepts `#t=90`
+        let (path, params) = rest.split_once(['?', '#']).unwrap_or((rest, ""));
+        let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+
+        let path_video_id = if host == "youtu.be" {
+            segments.next()
+        } else if is_youtube_host(&host) {
+            match (segments.next(), segments.next()) {
+                (Some("watch" | "playlist"), None) => None,
+                (Some("embed"), Some("videoseries")) => None,
+                (Some("shorts" | "embed" | "live" | "v" | "e"), Some(video_id)) => Some(video_id),
+                _ => return Err(VideoRefError::UnsupportedUrl),
+            }
+        } else {
+            return Err(VideoRefError::UnsupportedUrl);
+        };
+
+        let mut video_ref = Self {
+            video_id: path_video_id.map(ToOwned::to_owned),
+            ..Default::default()
+        };
+
+        for param in params.split(['&', '?', '#']) {
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+
+            match name {
+                "v" if path_video_id.is_none() => video_ref.video_id = Some(value.to_owned()),
+                "t" | "start" => {
+                    let start_seconds =
+                        parse_start_time(value).ok_or(VideoRefError::InvalidStartTime)?;
+                    video_ref.start_seconds = Some(start_seconds);
+                }
+                "list" if is_playlist_id(value) => video_ref.playlist_id = Some(value.to_owned()),
+                "list" => return Err(VideoRefError::InvalidPlaylist),
+                "index" => {
+                    let index: u32 = parse_digits(value).ok_or(VideoRefError::InvalidPlaylist)?;
+                    video_ref.playlist_index = Some(index.saturating_sub(1));
+                }
+                _ => {}
+            }
+        }
+
+        match &video_ref.video_id {
+            Some(video_id) if !is_video_id(video_id) => Err(VideoRefError::InvalidVideoId),
+            None if video_ref.playlist_id.is_none() => Err(VideoRefError::MissingVideo),
+            _ => Ok(video_ref),
+        }
+    }
+
+    /// Request for `YtPlayer::load_video`/`cue_video`, `None` for links of a whole playlist.
+    pub fn to_video_request(&self) -> Option<VideoRequest> {
+        let mut request = VideoRequest::by_id(self.video_id.clone()?);
+        request.start_seconds = self.start_seconds.map(f64::from);
+
+        Some(request)
+    }
+
+    /// Request for `YtPlayer::load_playlist`/`cue_playlist`, `None` without playlist ID.
+    pub fn to_playlist_request(&self) -> Option<PlaylistRequest> {
+        let mut request = PlaylistRequest::playlist(self.playlist_id.clone()?);
+        request.index = self.playlist_index;
+        request.start_seconds = self.start_seconds.map(f64::from);
+
+        Some(request)
+    }
+
+    /// Set video ID and start time of `options`, other options are kept.
+    ///
+    /// Playlists can't be set by player options, load them with `to_playlist_request`.
+    pub fn apply_to(&self, mut options: PlayerOptions) -> PlayerOptions {
+        if self.video_id.is_some() {
+            options.video_id = self.video_id.clone();
+        }
+
+        if let Some(start_seconds) = self.start_seconds {
+            let player_vars = options.player_vars.take().unwrap_or_default();
+            options.player_vars = Some(player_vars.start(start_seconds));
+        }
+
+        options
+    }
+}
+
+impl FromStr for VideoRef {
+    type Err = VideoRefError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl From<VideoRef> for PlayerOptions {
+    fn from(video_ref: VideoRef) -> Self {
+        video_ref.apply_to(PlayerOptions::new())
+    }
+}
+
+fn strip_scheme(text: &str) -> &str {
+    ["https://", "http://", "//"]
+        .iter()
+        .find_map(|scheme| text.strip_prefix(scheme))
+        .unwrap_or(text)
+}
+
+fn is_youtube_host(host: &str) -> bool {
+    let host = ["www.", "m.", "music."]
+        .iter()
+        .find_map(|prefix| host.strip_prefix(prefix))
+        .unwrap_or(host);
+
+    host == "youtube.com" || host == "youtube-nocookie.com"
+}
+
+fn is_id_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+fn is_video_id(text: &str) -> bool {
+    text.len() == VIDEO_ID_LENGTH && text.chars().all(is_id_char)
+}
+
+fn is_playlist_id(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(is_id_char)
+}
+
+// unlike `str::parse`, signs aren't accepted
+fn parse_digits(text: &str) -> Option<u32> {
+    if text.is_empty() || !text.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    text.parse().ok()
+}
+
+/// Seconds of `90`, `90s`, `1m30s` or `1h2m3s`, units must be descending.
+fn parse_start_time(text: &str) -> Option<u32> {
+    if let Some(seconds) = parse_digits(text) {
+        return Some(seconds);
+    }
+
+    if text.is_empty() {
+        return None;
+    }
+
+    let mut seconds: u32 = 0;
+    let mut rest = text;
+    let mut previous_unit = u32::MAX;
+
+    while !rest.is_empty() {
+        let unit_start = rest.find(|c: char| !c.is_ascii_digit())?;
+        let (number, unit) = rest.split_at(unit_start);
+
+        let unit_seconds = match unit.as_bytes()[0] {
+            b'h' => 3600,
+            b'm' => 60,
+            b's' => 1,
+            _ => return None,
+        };
+
+        if unit_seconds >= previous_unit {
+            return None;
+        }
+
+        let number = parse_digits(number)?;
+        seconds = seconds.checked_add(number.checked_mul(unit_seconds)?)?;
+
+        previous_unit = unit_seconds;
+        rest = &unit[1..];
+    }
+
+    Some(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ListType, PlayerVars, PlaylistSource};
+    use alloc::{format, vec};
+
+    const VIDEO_ID: &str = "M7lc1UVf-VE";
+
+    fn video(start_seconds: Option<u32>) -> VideoRef {
+        VideoRef {
+            video_id: Some(VIDEO_ID.to_owned()),
+            start_seconds,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parse_video_id() {
+        assert_eq!(Ok(video(None)), VideoRef::parse(VIDEO_ID));
+        assert_eq!(Ok(video(None)), VideoRef::parse(" M7lc1UVf-VE\n"));
+        assert_eq!(Ok(video(None)), "M7lc1UVf-VE".parse());
+
+        assert_eq!(
+            Err(VideoRefError::UnsupportedUrl),
+            VideoRef::parse("M7lc1UVf-V")
+        );
+        assert_eq!(
+            Err(VideoRefError::UnsupportedUrl),
+            VideoRef::parse("M7lc1UVf+VE")
+        );
+        assert_eq!(Err(VideoRefError::UnsupportedUrl), VideoRef::parse(""));
+    }
+
+    #[test]
+    fn parse_video_links() {
+        let links = vec![
+            "https://youtu.be/M7lc1UVf-VE",
+            "youtu.be/M7lc1UVf-VE",
+            "https://www.youtube.com/watch?v=M7lc1UVf-VE",
+            "http://youtube.com/watch?feature=share&v=M7lc1UVf-VE",
+            "https://m.youtube.com/watch?v=M7lc1UVf-VE#comments",
+            "https://music.youtube.com/watch?v=M7lc1UVf-VE",
+            "www.youtube.com/shorts/M7lc1UVf-VE",
+            "https://www.youtube.com/embed/M7lc1UVf-VE?autoplay=1",
+            "//www.youtube-nocookie.com/embed/M7lc1UVf-VE",
+            "https://www.youtube.com/live/M7lc1UVf-VE?feature=share",
+            "https://www.youtube.com/v/M7lc1UVf-VE?version=3",
+            "https://WWW.YouTube.com/watch?v=M7lc1UVf-VE",
+        ];
+
+        for link in links {
+            assert_eq!(Ok(video(None)), VideoRef::parse(link), "{}", link);
+        }
+    }
+
+    #[test]
+    fn parse_start_times() {
+        let links = vec![
+            ("https://youtu.be/M7lc1UVf-VE?t=90", 90),
+            ("https://youtu.be/M7lc1UVf-VE?t=90s", 90),
+            ("https://www.youtube.com/watch?v=M7lc1UVf-VE&t=1m30s", 90),
+            ("https://www.youtube.com/watch?v=M7lc1UVf-VE&t=1m", 60),
+            ("https://www.youtube.com/watch?v=M7lc1UVf-VE&t=1h2m3s", 3723),
+            ("https://www.youtube.com/watch?v=M7lc1UVf-VE&t=1h3s", 3603),
+            ("https://www.youtube.com/watch?v=M7lc1UVf-VE#t=45", 45),
+            ("https://www.youtube.com/embed/M7lc1UVf-VE?start=30", 30),
+        ];
+
+        for (link, start_seconds) in links {
+            assert_eq!(
+                Ok(video(Some(start_seconds))),
+                VideoRef::parse(link),
+                "{}",
+                link
+            );
+        }
+
+        for start_time in ["", "-5", "1.5", "1s2m", "1m1m", "m30s", "1d", "99999999999"] {
+            assert_eq!(
+                Err(VideoRefError::InvalidStartTime),
+                VideoRef::parse(&format!("youtu.be/M7lc1UVf-VE?t={}", start_time)),
+                "{}",
+                start_time
+            );
+        }
+    }
+
+    #[test]
+    fn parse_playlist_links() {
+        assert_eq!(
+            Ok(VideoRef {
+                video_id: Some(VIDEO_ID.to_owned()),
+                start_seconds: None,
+                playlist_id: Some("PLBCF2DAC6FFB574DE".to_owned()),
+                playlist_index: Some(2),
+            }),
+            VideoRef::parse(
+                "https://www.youtube.com/watch?v=M7lc1UVf-VE&list=PLBCF2DAC6FFB574DE&index=3"
+            )
+        );
+
+        let playlist = VideoRef {
+            playlist_id: Some("PLBCF2DAC6FFB574DE".to_owned()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Ok(playlist.clone()),
+            VideoRef::parse("https://www.youtube.com/playlist?list=PLBCF2DAC6FFB574DE")
+        );
+        assert_eq!(
+            Ok(playlist),
+            VideoRef::parse("https://www.youtube.com/embed/videoseries?list=PLBCF2DAC6FFB574DE")
+        );
+
+        assert_eq!(
+            Err(VideoRefError::InvalidPlaylist),
+            VideoRef::parse("https://www.youtube.com/playlist?list=PL%20x")
+        );
+        assert_eq!(
+            Err(VideoRefError::InvalidPlaylist),
+            VideoRef::parse("https://youtu.be/M7lc1UVf-VE?list=PLBCF2DAC6FFB574DE&index=x")
+        );
+    }
+
+    #[test]
+    fn parse_invalid_links() {
+        assert_eq!(
+            Err(VideoRefError::UnsupportedUrl),
+            VideoRef::parse("https://vimeo.com/watch?v=M7lc1UVf-VE")
+        );
+        assert_eq!(
+            Err(VideoRefError::UnsupportedUrl),
+            VideoRef::parse("https://www.youtube.com/channel/UC_x5XG1OV2P6uZZ5FSM9Ttw")
+        );
+        assert_eq!(
+            Err(VideoRefError::InvalidVideoId),
+            VideoRef::parse("https://www.youtube.com/watch?v=M7lc1UVf")
+        );
+        assert_eq!(
+            Err(VideoRefError::InvalidVideoId),
+            VideoRef::parse("https://youtu.be/M7lc1UVf-VE.mp4")
+        );
+        assert_eq!(
+            Err(VideoRefError::MissingVideo),
+            VideoRef::parse("https://www.youtube.com/watch?feature=share")
+        );
+        assert_eq!(
+            Err(VideoRefError::MissingVideo),
+            VideoRef::parse("https://youtu.be/")
+        );
+    }
+
+    #[test]
+    fn video_ref_requests() {
+        let video_ref = VideoRef::parse(
+            "https://www.youtube.com/watch?v=M7lc1UVf-VE&t=1m30s&list=PLBCF2DAC6FFB574DE&index=3",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(VideoRequest::by_id(VIDEO_ID.to_owned()).start_seconds(90.0)),
+            video_ref.to_video_request()
+        );
+
+        let playlist_request = video_ref.to_playlist_request().unwrap();
+
+        assert_eq!(
+            PlaylistSource::List {
+                list_type: ListType::Playlist,
+                list: "PLBCF2DAC6FFB574DE".to_owned(),
+            },
+            playlist_request.source
+        );
+        assert_eq!(Some(2), playlist_request.index);
+        assert_eq!(Some(90.0), playlist_request.start_seconds);
+
+        assert_eq!(None, video(None).to_playlist_request());
+        assert_eq!(None, VideoRef::default().to_video_request());
+    }
+
+    #[test]
+    fn video_ref_player_options() {
+        let options = PlayerOptions::from(video(Some(90)));
+
+        assert_eq!(Some(VIDEO_ID.to_owned()), options.video_id);
+        assert_eq!(Some(90), options.player_vars.unwrap().start);
+
+        // keeps other options and player parameters
+        let options = video(Some(30)).apply_to(
+            PlayerOptions::new()
+                .width(640)
+                .player_vars(PlayerVars::new().autoplay(true)),
+        );
+
+        assert_eq!(Some(640), options.width);
+        assert_eq!(
+            Some(PlayerVars::new().autoplay(true).start(30)),
+            options.player_vars
+        );
+
+        assert_eq!(None, PlayerOptions::from(video(None)).player_vars);
+    }
+}
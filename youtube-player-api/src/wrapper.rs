@@ -1,47 +1,189 @@
 mod api;
+mod player_commands;
+mod player_error;
 mod player_events;
+mod player_monitor;
 mod player_options;
+mod player_playlist;
+mod player_poller;
+mod player_quality;
+mod player_size;
+mod player_snapshot;
 mod player_state;
-
-use alloc::{borrow::ToOwned, boxed::Box, rc::Rc, string::String, vec, vec::Vec};
-use core::{cell::RefCell, ops::Deref};
-
-use crate::{controllable_promise, init_yt_api, PromiseConstructorFunction};
-
-pub use self::player_events::PlayerEvents;
-pub use self::player_options::{PlayerOptions, PlayerVars};
+mod player_target;
+mod player_video;
+mod player_volume;
+
+use alloc::{borrow::ToOwned, boxed::Box, format, rc::Rc, string::String, vec, vec::Vec};
+use core::{
+    any::Any,
+    cell::RefCell,
+    future::Future,
+    ops::Deref,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+use crate::{
+    get_yt_global, load_yt_api, loader_host, signal::Signal, ApiLoadError, CommandError,
+    PlayerInitError,
+};
+
+pub use self::player_commands::PendingCommandPolicy;
+pub use self::player_error::PlayerError;
+pub use self::player_events::{PlayerEvent, PlayerEventName, PlayerEvents};
+pub use self::player_monitor::{MonitorOptions, Seek, Stall};
+pub use self::player_options::{IvLoadPolicy, PlayerOptions, PlayerVars};
+pub use self::player_playlist::{ListType, PlaylistRequest, PlaylistSource};
+pub use self::player_quality::PlaybackQuality;
+pub use self::player_snapshot::PlayerSnapshot;
 pub use self::player_state::PlayerState;
+pub use self::player_target::PlayerTarget;
+pub use self::player_video::{VideoRequest, VideoSource};
+pub use self::player_volume::{Volume, VolumeChange};
+
+pub(crate) use self::api::LoaderOptionsObject;
 
-use self::api::PlayerInstance;
+use self::api::{PlayerInstance, PlayerSnapshotObject, PlaylistRequestObject, VideoRequestObject};
+use self::player_commands::{Command, CommandQueue};
+use self::player_monitor::{MonitorEvent, PlaybackMonitor};
+use self::player_poller::Poller;
+use self::player_size::ResizeBinding;
 
 use hashbrown::HashMap;
-use js_sys::{Array, Function, Object, Promise, Reflect};
-use serde_wasm_bindgen::from_value;
+use js_sys::{Array, Date, Function, Object, Promise, Reflect};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_wasm_bindgen::{from_value, to_value};
 use wasm_bindgen::{prelude::*, JsCast};
-use wasm_bindgen_futures::{future_to_promise, JsFuture};
-use web_sys::{console, window};
+use wasm_bindgen_futures::future_to_promise;
+use web_sys::{console, Element, HtmlIFrameElement};
+
+// handler IDs are unique across all players
+static NEXT_HANDLER_ID: AtomicU32 = AtomicU32::new(1);
+
+// IDs for target elements without their own ID
+static NEXT_ELEMENT_ID: AtomicU32 = AtomicU32::new(1);
+
+/// Serialize into plain JS objects, `serde_wasm_bindgen` would create a `Map` for flattened fields.
+pub(crate) fn to_js_object<T: Serialize + ?Sized>(
+    value: &T,
+) -> Result<JsValue, serde_wasm_bindgen::Error> {
+    value.serialize(&serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true))
+}
+
+/// Function of a handler, typed handlers own their closure until the handler is removed.
+#[derive(Debug)]
+struct HandlerFunction {
+    function: Function,
+    _closure: Option<Box<dyn Any>>,
+}
+
+impl HandlerFunction {
+    fn from_closure<T: ?Sized + 'static>(closure: Closure<T>) -> Self {
+        Self {
+            function: closure.as_ref().unchecked_ref::<Function>().clone(),
+            _closure: Some(Box::new(closure)),
+        }
+    }
+}
+
+impl From<Function> for HandlerFunction {
+    fn from(function: Function) -> Self {
+        Self {
+            function,
+            _closure: None,
+        }
+    }
+}
 
 #[derive(Debug)]
 struct EventHandler {
+    id: u32,
     namespace: Option<String>,
-    handler: Rc<Function>,
+    handler: Rc<HandlerFunction>,
+    once: bool,
+}
+
+impl EventHandler {
+    fn new(namespace: Option<&str>, handler: HandlerFunction, once: bool) -> Self {
+        Self {
+            id: NEXT_HANDLER_ID.fetch_add(1, Ordering::Relaxed),
+            namespace: namespace.map(|ns| ns.to_owned()),
+            handler: Rc::new(handler),
+            once,
+        }
+    }
 }
 
 type EventHandlerHashmap = Rc<RefCell<HashMap<String, Vec<EventHandler>>>>;
 
+type PlayerReadySignal = Signal<Result<(), PlayerInitError>>;
+
+// instance is shared with the ready handler, which replays queued commands
+type SharedPlayerInstance = Rc<RefCell<Option<PlayerInstance>>>;
+
+type SharedCommandQueue = Rc<RefCell<CommandQueue<PlayerInstance>>>;
+
+#[derive(Debug, Default)]
+struct ResizeState {
+    aspect_ratio: Option<f64>,
+    binding: Option<ResizeBinding>,
+}
+
+// closures are kept alive until the player gets destroyed, instead of leaking them to JS
+type ClosureStorage = Rc<RefCell<Vec<Box<dyn Any>>>>;
+
 #[wasm_bindgen(js_name = YoutubePlayer)]
 #[derive(Debug)]
 pub struct YtPlayer {
+    player_id: String,
     is_ready: Rc<RefCell<bool>>,
-    player_loaded: Rc<Promise>,
-    player_instance: Option<PlayerInstance>,
+    player_loaded: PlayerReadySignal,
+    player_instance: SharedPlayerInstance,
     event_handlers: EventHandlerHashmap,
+    closures: ClosureStorage,
+    pending_command_policy: PendingCommandPolicy,
+    command_queue: SharedCommandQueue,
+    time_update_poller: RefCell<Poller>,
+    monitor_poller: RefCell<Poller>,
+    resize: Rc<RefCell<ResizeState>>,
 }
 
 #[wasm_bindgen(js_class = YoutubePlayer)]
 impl YtPlayer {
     #[wasm_bindgen(constructor)]
     pub fn new(player_id: &str, options: Object) -> Self {
+        Self::with_target(player_id.to_owned(), player_id.into(), options)
+    }
+
+    /// Create a player for the given element, an ID is assigned if the element has none.
+    #[wasm_bindgen(js_name = fromElement)]
+    pub fn from_element(element: &Element, options: Object) -> Self {
+        if element.id().is_empty() {
+            let element_id = NEXT_ELEMENT_ID.fetch_add(1, Ordering::Relaxed);
+            element.set_id(&format!("yt-player-{}", element_id));
+        }
+
+        Self::with_target(element.id(), element.into(), options)
+    }
+
+    /// Create a player inside a new container element, appended to `parent`.
+    #[wasm_bindgen(js_name = inContainer)]
+    pub fn in_container(parent: &Element, options: Object) -> Result<YtPlayer, PlayerInitError> {
+        let document = parent.owner_document().ok_or(ApiLoadError::NoWindow)?;
+
+        let container = document
+            .create_element("div")
+            .map_err(|_| PlayerInitError::ContainerCreation)?;
+
+        parent
+            .append_child(&container)
+            .map_err(|_| PlayerInitError::ContainerCreation)?;
+
+        Ok(Self::from_element(&container, options))
+    }
+
+    /// Target can be an element or the ID of an element.
+    fn with_target(player_id: String, target: JsValue, options: Object) -> Self {
         // create new or use existing options object
         let mut options_object = Object::new();
 
@@ -54,27 +196,56 @@ impl YtPlayer {
 
         // prepare flags and function to signal a ready player
         let is_ready_handle = Rc::new(RefCell::new(false));
-        let (player_ready, ready_resolver, ready_rejecter) = controllable_promise();
+        let player_ready: PlayerReadySignal = Signal::new();
+        let player_instance: SharedPlayerInstance = Rc::new(RefCell::new(None));
+        let command_queue: SharedCommandQueue = Rc::new(RefCell::new(CommandQueue::new()));
 
         let handlers: EventHandlerHashmap = Rc::new(RefCell::new(HashMap::new()));
+        let closures: ClosureStorage = Rc::new(RefCell::new(vec![]));
 
         // add own wrapper ready event handler to list (loading signal for promise)
-        let new_handler = Self::create_ready_event_handler(is_ready_handle.clone(), ready_resolver);
+        let new_handler = Self::create_ready_event_handler(
+            is_ready_handle.clone(),
+            player_ready.clone(),
+            player_instance.clone(),
+            command_queue.clone(),
+        );
 
         Self::add_event_handler_fn(
-            None,
             handlers.clone(),
-            ("ready", None),
-            new_handler.into_js_value().unchecked_into::<Function>(),
+            PlayerEvents::READY,
+            EventHandler::new(
+                None,
+                Self::store_closure(&closures, new_handler).into(),
+                false,
+            ),
         );
 
+        // listen to all API events, handlers (e.g. wildcard handlers) can be added at any time
+        for event_name in PlayerEvents::API_EVENTS {
+            handlers
+                .deref()
+                .borrow_mut()
+                .entry(event_name.to_owned())
+                .or_default();
+        }
+
+        // read wrapper options, don't forward to original API
+        let pending_command_policy =
+            Self::take_wrapper_option(&options_object, "pendingCommands").unwrap_or_default();
+        let time_update_interval: Option<u32> =
+            Self::take_wrapper_option(&options_object, "timeUpdateInterval");
+        let playback_monitor: Option<MonitorOptions> =
+            Self::take_wrapper_option(&options_object, "playbackMonitor");
+        let aspect_ratio: Option<f64> = Self::take_wrapper_option(&options_object, "aspectRatio");
+
         // read given events from options
         let previous_events = Reflect::get(&options_object, &"events".into()).ok();
 
         if let Some(previous_events) = previous_events {
             if !previous_events.is_undefined() && !previous_events.is_null() {
                 // delete old event property, don't forward to original API
-                let _success = Reflect::delete_property(&options_object, &"events".into()).unwrap();
+                let _success = Reflect::delete_property(&options_object, &"events".into());
 
                 // extract given events and add them to wrapper hashmap
                 let event_options = previous_events.dyn_into::<Object>();
@@ -83,25 +254,29 @@ impl YtPlayer {
                     let property_names = Object::get_own_property_names(&event_options);
 
                     for property_name in property_names.iter() {
-                        let event_name: String = from_value(property_name).unwrap();
+                        let event_name = match property_name.as_string() {
+                            Some(event_name) => event_name,
+                            None => continue,
+                        };
 
                         let descriptor = Object::get_own_property_descriptor(
                             &event_options,
                             &event_name.clone().into(),
                         );
 
-                        let descriptor_value = Reflect::get(&descriptor, &"value".into()).unwrap();
+                        let descriptor_value = Reflect::get(&descriptor, &"value".into());
 
-                        if let Ok(handler_fn) = descriptor_value.dyn_into::<Function>() {
+                        if let Ok(handler_fn) =
+                            descriptor_value.and_then(|value| value.dyn_into::<Function>())
+                        {
                             let namespaced_event = PlayerEvents::get_namespaced_event(&event_name);
 
                             match namespaced_event {
-                                Ok(namespaced_event) => {
+                                Ok((event_name, namespace)) => {
                                     Self::add_event_handler_fn(
-                                        None,
                                         handlers.clone(),
-                                        namespaced_event,
-                                        handler_fn,
+                                        event_name,
+                                        EventHandler::new(namespace, handler_fn.into(), false),
                                     );
                                 }
                                 Err(error) => console::error_1(&error.into()),
@@ -117,9 +292,16 @@ impl YtPlayer {
         let events_object = Object::new();
 
         for handler in handlers.deref().borrow().iter() {
+            // synthetic events are dispatched by the wrapper, Youtube API doesn't know them
+            if !Self::is_api_listener_needed(handler.0) {
+                continue;
+            }
+
             let handler_name = PlayerEvents::get_handler_name(handler.0);
-            let handler_wrapper =
-                Self::create_event_handler_wrapper(handlers.clone(), handler.0).into_js_value();
+            let handler_wrapper = Self::store_closure(
+                &closures,
+                Self::create_event_handler_wrapper(handlers.clone(), handler.0),
+            );
 
             match handler_name {
                 Ok(handler_name) => {
@@ -135,59 +317,112 @@ impl YtPlayer {
 
         let _success = Reflect::set(&options_object, &"events".into(), &events_object);
 
-        // create a youtube player instance
-        let yt_global = Reflect::get(&window().unwrap(), &"YT".into()).unwrap();
-        let player_constructor = Reflect::get(&yt_global, &"Player".into()).unwrap();
+        // use host configured for the API loader, if the player doesn't set its own
+        if let Some(host) = loader_host() {
+            let player_host = Reflect::get(&options_object, &"host".into());
 
-        let player_instance = Reflect::construct(
-            player_constructor.dyn_ref::<Function>().unwrap(),
-            &Array::from_iter([JsValue::from(player_id), options_object.into()]),
-        )
-        .unwrap();
+            if player_host.map_or(true, |player_host| player_host.is_undefined()) {
+                let _success = Reflect::set(&options_object, &"host".into(), &host.into());
+            }
+        }
 
-        // player member "i" is not null on successful binding
-        let player_successful = Reflect::get(&player_instance, &"i".into()).unwrap();
+        // create a youtube player instance
+        match Self::construct_player(&target, options_object) {
+            Ok(constructed_instance) => {
+                player_instance.replace(Some(constructed_instance));
+            }
+            Err(error) => {
+                console::error_1(&error.into());
 
-        let player_instance = if !player_successful.is_undefined() && !player_successful.is_null() {
-            Some(player_instance.unchecked_into::<PlayerInstance>())
-        } else {
-            // signal player loading failed
-            ready_rejecter
-                .borrow()
-                .as_ref()
-                .unwrap()
-                .apply(&JsValue::null(), &Array::new())
-                .unwrap();
-
-            None
-        };
+                // signal player loading failed
+                player_ready.resolve(Err(error));
+            }
+        }
 
-        Self {
+        let player = Self {
+            player_id,
             is_ready: is_ready_handle,
-            player_loaded: Rc::new(player_ready),
+            player_loaded: player_ready,
             player_instance,
             event_handlers: handlers,
-        }
+            closures,
+            pending_command_policy,
+            command_queue,
+            time_update_poller: RefCell::new(Poller::new()),
+            monitor_poller: RefCell::new(Poller::new()),
+            resize: Rc::new(RefCell::new(ResizeState::default())),
+        };
+
+        player.set_time_update_interval(time_update_interval);
+        player.set_playback_monitor(playback_monitor);
+        player.set_aspect_ratio(aspect_ratio);
+
+        player
     }
 
-    pub fn create(player_id: &str, options: Object) -> Promise {
-        let player_id = player_id.to_owned();
+    /// Read and remove an option only used by the wrapper, invalid values are logged and ignored.
+    fn take_wrapper_option<T: DeserializeOwned>(options_object: &Object, name: &str) -> Option<T> {
+        let option = Reflect::get(options_object, &name.into())
+            .ok()
+            .filter(|option| !option.is_undefined())
+            .and_then(|option| match from_value(option) {
+                Ok(option) => Some(option),
+                Err(error) => {
+                    console::error_1(&error.into());
+                    None
+                }
+            });
 
-        future_to_promise(async move {
-            let _is_api_ready = JsFuture::from(init_yt_api()).await?;
+        let _success = Reflect::delete_property(options_object, &name.into());
 
-            let instance = Self::new(&player_id, options);
+        option
+    }
 
-            let player_init_complete = instance.player_loaded.deref().clone();
-            let _is_player_ready = JsFuture::from(player_init_complete).await?;
+    pub fn create(player_id: &str, options: Object) -> Promise {
+        let target = PlayerTarget::from(player_id);
+
+        future_to_promise(async move {
+            let instance = Self::create_instance(target, options).await?;
 
             Ok(instance.into())
         })
     }
 
-    fn get_player_instance(&self) -> Option<&PlayerInstance> {
+    fn construct_player(
+        target: &JsValue,
+        options_object: Object,
+    ) -> Result<PlayerInstance, PlayerInitError> {
+        let yt_global = get_yt_global()?;
+        let player_constructor = Reflect::get(&yt_global, &"Player".into())
+            .ok()
+            .and_then(|constructor| constructor.dyn_into::<Function>().ok())
+            .ok_or(ApiLoadError::YtGlobalMissing)?;
+
+        let player_instance = Reflect::construct(
+            &player_constructor,
+            &Array::from_iter([target.clone(), options_object.into()]),
+        )
+        .map_err(|_| ApiLoadError::PlayerConstructFailed)?;
+
+        // player member "i" is not null on successful binding
+        let player_successful = Reflect::get(&player_instance, &"i".into())
+            .map_err(|_| PlayerInitError::PlayerBinding)?;
+
+        if player_successful.is_undefined() || player_successful.is_null() {
+            return Err(PlayerInitError::PlayerBinding);
+        }
+
+        Ok(player_instance.unchecked_into::<PlayerInstance>())
+    }
+
+    /// Error of a player which couldn't be constructed, e.g. because the API isn't loaded.
+    pub(crate) fn init_error(&self) -> Option<PlayerInitError> {
+        self.player_loaded.value().and_then(Result::err)
+    }
+
+    fn get_player_instance(&self) -> Option<PlayerInstance> {
         if *self.is_ready.borrow() {
-            return self.player_instance.as_ref();
+            return self.player_instance.borrow().clone();
         }
 
         console::warn_1(&"Player isn't ready yet!".into());
@@ -195,28 +430,209 @@ impl YtPlayer {
         None
     }
 
-    fn run_player<F>(&self, cb: F)
+    /// Run a player command, commands issued before the player is ready are handled
+    /// according to the `PendingCommandPolicy`.
+    fn run_player<F>(&self, cb: F) -> Result<(), CommandError>
+    where
+        F: FnOnce(&PlayerInstance) + 'static,
+    {
+        self.run_player_with_policy(self.pending_command_policy, cb)
+    }
+
+    /// Run an internal command, which is always queued until the player is ready.
+    fn run_when_ready<F>(&self, cb: F)
     where
-        F: FnOnce(&PlayerInstance),
+        F: FnOnce(&PlayerInstance) + 'static,
     {
-        let player_instance_option = self.get_player_instance();
-        player_instance_option.map(cb);
+        // queued commands are only rejected if the player isn't available anymore
+        let _queued = self.run_player_with_policy(PendingCommandPolicy::Queue, cb);
+    }
+
+    fn run_player_with_policy<F>(
+        &self,
+        pending_command_policy: PendingCommandPolicy,
+        cb: F,
+    ) -> Result<(), CommandError>
+    where
+        F: FnOnce(&PlayerInstance) + 'static,
+    {
+        // clone instance, so commands are able to destroy the player
+        let player_instance = self.player_instance.borrow().clone();
+
+        let player_instance = match player_instance {
+            Some(player_instance) => player_instance,
+            // player couldn't be constructed or was already destroyed, regardless of the policy
+            None => return Err(CommandError::Unavailable),
+        };
+
+        if *self.is_ready.borrow() {
+            cb(&player_instance);
+            return Ok(());
+        }
+
+        match pending_command_policy {
+            PendingCommandPolicy::Drop => console::warn_1(&"Player isn't ready yet!".into()),
+            PendingCommandPolicy::Queue => self.command_queue.borrow_mut().push(Box::new(cb)),
+            PendingCommandPolicy::Error => return Err(CommandError::NotReady),
+        }
+
+        Ok(())
+    }
+
+    /// Emit `timeUpdate` and `progress` events every `interval` milliseconds while the video is
+    /// playing or buffering, `undefined` or `0` stops the events.
+    #[wasm_bindgen(js_name = setTimeUpdateInterval)]
+    pub fn set_time_update_interval(&self, interval: Option<u32>) {
+        let mut poller = self.time_update_poller.borrow_mut();
+
+        match interval.filter(|interval| *interval > 0) {
+            Some(interval) => poller.start(
+                interval,
+                Self::create_time_update_poll(
+                    self.is_ready.clone(),
+                    self.player_instance.clone(),
+                    self.event_handlers.clone(),
+                ),
+            ),
+            None => poller.stop(),
+        }
+    }
+
+    fn create_time_update_poll(
+        is_ready: Rc<RefCell<bool>>,
+        player_instance: SharedPlayerInstance,
+        handler_hashmap: EventHandlerHashmap,
+    ) -> Box<dyn FnMut()> {
+        let mut loaded_fraction = None;
+
+        Box::new(move || {
+            if !*is_ready.borrow() {
+                return;
+            }
+
+            let instance = match player_instance.borrow().clone() {
+                Some(instance) => instance,
+                None => return,
+            };
+
+            let state: PlayerState = from_value(instance.get_player_state()).unwrap_or_default();
+
+            if !matches!(state, PlayerState::Playing | PlayerState::Buffering) {
+                return;
+            }
+
+            Self::dispatch_synthetic_event(
+                &handler_hashmap,
+                PlayerEvents::TIME_UPDATE,
+                instance.get_current_time().into(),
+            );
+
+            // progress is only emitted on changes, loading usually stops long before the video ends
+            let current_fraction = instance.get_video_loaded_fraction();
+
+            if loaded_fraction != Some(current_fraction) {
+                loaded_fraction = Some(current_fraction);
+
+                Self::dispatch_synthetic_event(
+                    &handler_hashmap,
+                    PlayerEvents::PROGRESS,
+                    current_fraction.into(),
+                );
+            }
+        })
+    }
+
+    #[wasm_bindgen(js_name = setPlaybackMonitor)]
+    pub fn js_set_playback_monitor(&self, options: JsValue) {
+        if options.is_undefined() || options.is_null() {
+            return self.set_playback_monitor(None);
+        }
+
+        match from_value(options) {
+            Ok(options) => self.set_playback_monitor(Some(options)),
+            Err(error) => console::error_1(&error.into()),
+        }
+    }
+
+    /// Iframe of the player, `undefined` if the player isn't ready.
+    #[wasm_bindgen(js_name = getIframe)]
+    pub fn iframe(&self) -> Option<HtmlIFrameElement> {
+        self.get_player_instance()
+            .and_then(|instance| instance.get_iframe().dyn_into().ok())
+    }
+
+    /// Set size of the player iframe in pixels.
+    #[wasm_bindgen(js_name = setSize)]
+    pub fn set_size(&self, width: f64, height: f64) -> Result<(), CommandError> {
+        self.run_player(move |instance| instance.set_size(width, height))
+    }
+
+    /// Keep the player at a fixed aspect ratio (width / height, e.g. `16 / 9`) filling the width
+    /// of its parent element, `undefined` stops resizing.
+    #[wasm_bindgen(js_name = setAspectRatio)]
+    pub fn set_aspect_ratio(&self, aspect_ratio: Option<f64>) {
+        let aspect_ratio =
+            aspect_ratio.filter(|aspect_ratio| aspect_ratio.is_finite() && *aspect_ratio > 0.0);
+
+        {
+            let mut resize = self.resize.borrow_mut();
+            resize.aspect_ratio = aspect_ratio;
+            resize.binding = None;
+        }
+
+        if aspect_ratio.is_none() {
+            return;
+        }
+
+        let resize = self.resize.clone();
+
+        // the iframe is only available after the player is ready
+        self.run_when_ready(move |instance| {
+            let mut resize = resize.borrow_mut();
+
+            // aspect ratio could have been changed while waiting
+            if let Some(aspect_ratio) = resize.aspect_ratio {
+                match ResizeBinding::new(instance, aspect_ratio) {
+                    Ok(binding) => resize.binding = Some(binding),
+                    Err(error) => console::error_1(&error),
+                }
+            }
+        });
+    }
+
+    /// ID of the element the player was created for.
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> String {
+        self.player_id.clone()
+    }
+
+    /// Whether the player fired its `ready` event, commands run immediately from then on.
+    #[wasm_bindgen(js_name = isReady)]
+    pub fn is_ready(&self) -> bool {
+        *self.is_ready.borrow()
     }
 
     fn create_ready_event_handler(
         is_ready: Rc<RefCell<bool>>,
-        ready_resolver: PromiseConstructorFunction,
+        player_ready: PlayerReadySignal,
+        player_instance: SharedPlayerInstance,
+        command_queue: SharedCommandQueue,
     ) -> Closure<dyn FnMut(JsValue)> {
         Closure::wrap(Box::new(move |_event: JsValue| {
             *is_ready.deref().borrow_mut() = true;
 
+            // replay commands issued before the player was ready, in order
+            let commands = command_queue.borrow_mut().take();
+            let player_instance = player_instance.borrow().clone();
+
+            if let Some(player_instance) = player_instance {
+                for command in commands {
+                    command(&player_instance);
+                }
+            }
+
             // signal player loading complete
-            ready_resolver
-                .borrow()
-                .as_ref()
-                .unwrap()
-                .apply(&JsValue::null(), &Array::new())
-                .unwrap();
+            player_ready.resolve(Ok(()));
         }) as Box<dyn FnMut(JsValue)>)
     }
 
@@ -230,109 +646,214 @@ impl YtPlayer {
 
         Closure::wrap(Box::new(move |event: JsValue| {
             // remove target property (unwrapped Youtube API instance) from event
-            if Reflect::get(&event, &target_property.into()).is_ok() {
-                let _success = Reflect::delete_property(
-                    event.dyn_ref::<Object>().unwrap(),
-                    &target_property.into(),
-                )
-                .unwrap();
+            if let Some(event_object) = event.dyn_ref::<Object>() {
+                let _success = Reflect::delete_property(event_object, &target_property.into());
             }
 
-            // only use event as parameter, if it still contains other data
-            let params = if Reflect::own_keys(&event).unwrap().length() > 0 {
-                Array::from_iter([event])
-            } else {
-                Array::new()
-            };
-
-            for handler in handler_hashmap.borrow().get(&event_name).unwrap().iter() {
-                handler.handler.apply(&JsValue::null(), &params).unwrap();
-            }
+            Self::dispatch_event(&handler_hashmap, &event_name, event);
         }) as Box<dyn FnMut(JsValue)>)
     }
 
-    fn add_event_handler_fn(
-        instance: Option<&PlayerInstance>,
-        handler_hashmap: EventHandlerHashmap,
-        namespaced_event: (&str, Option<&str>),
-        handler_fn: Function,
-    ) {
-        let (event_name, namespace) = namespaced_event;
+    fn dispatch_event(handler_hashmap: &EventHandlerHashmap, event_name: &str, event: JsValue) {
+        // only use event as parameter, if it still contains other data
+        let has_data =
+            event.is_object() && Reflect::own_keys(&event).is_ok_and(|keys| keys.length() > 0);
 
-        let handler_name = PlayerEvents::get_handler_name(event_name);
+        let params = if has_data {
+            Array::from_iter([event])
+        } else {
+            Array::new()
+        };
 
-        match handler_name {
-            Ok(handler_name) => {
-                let mut hashmap = handler_hashmap.deref().borrow_mut();
+        // clone handlers first, so they are able to (un)register handlers themselves
+        let (handlers, wildcard_handlers) = {
+            let mut hashmap = handler_hashmap.deref().borrow_mut();
 
-                if !hashmap.contains_key(event_name) {
-                    hashmap.insert(event_name.to_owned(), vec![]);
+            (
+                Self::take_handlers(&mut hashmap, event_name),
+                Self::take_handlers(&mut hashmap, PlayerEvents::WILDCARD),
+            )
+        };
 
-                    if let Some(instance) = instance {
-                        // add event handler wrapper to original Youtube API, if it has a brandnew key
-                        // doesn't use hashmap.entry(…).or_insert(…) with check for empty vector,
-                        // because vector could be empty after removing events too
-                        instance.add_event_listener(
-                            handler_name.into(),
-                            Self::create_event_handler_wrapper(handler_hashmap.clone(), event_name)
-                                .into_js_value(),
-                        );
-                    }
-                }
+        for handler in handlers.iter() {
+            Self::call_handler(handler, &params);
+        }
 
-                let handler_vec = hashmap.get_mut(event_name).unwrap();
+        if !wildcard_handlers.is_empty() {
+            // wildcard handlers get the event name as first parameter
+            let wildcard_params = Array::of1(&event_name.into()).concat(&params);
 
-                // add event handler to handler list
-                handler_vec.push(EventHandler {
-                    namespace: namespace.map(|ns| ns.to_owned()),
-                    handler: Rc::new(handler_fn),
-                });
+            for handler in wildcard_handlers.iter() {
+                Self::call_handler(handler, &wildcard_params);
             }
-            Err(error) => console::error_1(&error.into()),
         }
     }
 
-    pub fn on(&self, event_name: &str, handler_fn: JsValue) {
-        let namespaced_event = PlayerEvents::get_namespaced_event(event_name);
+    fn take_handlers(
+        hashmap: &mut HashMap<String, Vec<EventHandler>>,
+        event_name: &str,
+    ) -> Vec<Rc<HandlerFunction>> {
+        match hashmap.get_mut(event_name) {
+            Some(handler_vec) => {
+                let handlers = handler_vec
+                    .iter()
+                    .map(|handler| handler.handler.clone())
+                    .collect();
+
+                // one-shot handlers are removed before running, so they can't be triggered twice,
+                // their closures are released once the returned handlers are dropped
+                handler_vec.retain(|handler| !handler.once);
+
+                handlers
+            }
+            None => vec![],
+        }
+    }
 
-        if let Err(error) = namespaced_event {
-            console::error_1(&error.into());
-            return;
+    fn call_handler(handler: &HandlerFunction, params: &Array) {
+        // a throwing handler must neither abort the module nor skip the remaining handlers
+        if let Err(error) = handler.function.apply(&JsValue::null(), params) {
+            console::error_1(&error);
         }
+    }
 
-        if let Ok(handler_fn) = handler_fn.dyn_into::<Function>() {
-            self.run_player(move |instance| {
-                Self::add_event_handler_fn(
-                    Some(instance),
-                    self.event_handlers.clone(),
-                    namespaced_event.unwrap(),
-                    handler_fn,
-                );
+    /// Emit an event which isn't provided by the Youtube API, data is available under `event.data`.
+    fn dispatch_synthetic_event(
+        handler_hashmap: &EventHandlerHashmap,
+        event_name: &str,
+        data: JsValue,
+    ) {
+        let event = Object::new();
+        let _success = Reflect::set(&event, &"data".into(), &data);
+
+        Self::dispatch_event(handler_hashmap, event_name, event.into());
+    }
+
+    /// Keep closure alive as long as the player exists and return it as JS function.
+    fn store_closure<T: ?Sized + 'static>(
+        closures: &ClosureStorage,
+        closure: Closure<T>,
+    ) -> Function {
+        let function = closure.as_ref().unchecked_ref::<Function>().clone();
+        closures.deref().borrow_mut().push(Box::new(closure));
+
+        function
+    }
+
+    /// Synthetic and wildcard events are dispatched by the wrapper itself.
+    fn is_api_listener_needed(event_name: &str) -> bool {
+        !PlayerEvents::is_synthetic(event_name) && event_name != PlayerEvents::WILDCARD
+    }
+
+    /// Add handler to the handler list, returns `true` if the event had no handler list before.
+    fn add_event_handler_fn(
+        handler_hashmap: EventHandlerHashmap,
+        event_name: &str,
+        event_handler: EventHandler,
+    ) -> bool {
+        let mut hashmap = handler_hashmap.deref().borrow_mut();
+
+        // doesn't use hashmap.entry(…).or_insert(…) with check for empty vector,
+        // because vector could be empty after removing events too
+        let is_new_event = !hashmap.contains_key(event_name);
+
+        hashmap
+            .entry(event_name.to_owned())
+            .or_default()
+            .push(event_handler);
+
+        is_new_event
+    }
+
+    /// Register an event handler and return its ID for `off_id`.
+    ///
+    /// Event names may contain a namespace path (`stateChange.sync.drift`),
+    /// handlers for event `*` receive every event with its name as first parameter.
+    pub fn on(&self, event_name: &str, handler_fn: JsValue) -> Option<u32> {
+        let handler_fn = handler_fn.dyn_into::<Function>().ok()?;
+        self.register_handler(event_name, handler_fn.into(), false)
+    }
+
+    /// Same as `on`, but the handler is removed after it ran once.
+    pub fn once(&self, event_name: &str, handler_fn: JsValue) -> Option<u32> {
+        let handler_fn = handler_fn.dyn_into::<Function>().ok()?;
+        self.register_handler(event_name, handler_fn.into(), true)
+    }
+
+    fn register_handler(
+        &self,
+        event_name: &str,
+        handler_fn: HandlerFunction,
+        once: bool,
+    ) -> Option<u32> {
+        let namespaced_event = PlayerEvents::get_namespaced_event(event_name);
+
+        let (event_name, namespace) = match namespaced_event {
+            Ok(namespaced_event) => namespaced_event,
+            Err(error) => {
+                console::error_1(&error.into());
+                return None;
+            }
+        };
+
+        let handler_name = match PlayerEvents::get_handler_name(event_name) {
+            Ok(handler_name) => handler_name,
+            Err(error) => {
+                console::error_1(&error.into());
+                return None;
+            }
+        };
+
+        let event_handler = EventHandler::new(namespace, handler_fn, once);
+        let handler_id = event_handler.id;
+
+        // handlers are registered immediately, even if the player isn't ready yet
+        let is_new_event =
+            Self::add_event_handler_fn(self.event_handlers.clone(), event_name, event_handler);
+
+        // add event handler wrapper to original Youtube API, if it has a brandnew key
+        if is_new_event && Self::is_api_listener_needed(event_name) {
+            let handler_wrapper = Self::store_closure(
+                &self.closures,
+                Self::create_event_handler_wrapper(self.event_handlers.clone(), event_name),
+            );
+
+            self.run_when_ready(move |instance| {
+                instance.add_event_listener(handler_name.into(), handler_wrapper.into())
             });
         }
+
+        Some(handler_id)
     }
 
+    /// Remove handlers of an event, only those within the namespace if one is given.
+    ///
+    /// Namespaces are hierarchical, `stateChange.sync` also removes `stateChange.sync.drift`.
+    /// An empty event name (`.sync`) removes the namespace from all events.
     pub fn off(&self, event_name: &str) {
         let namespaced_event = PlayerEvents::get_namespaced_event(event_name);
 
-        if let Err(error) = namespaced_event {
-            console::error_1(&error.into());
-            return;
-        }
-
-        let (event_name, namespace) = namespaced_event.unwrap();
+        let (event_name, namespace) = match namespaced_event {
+            Ok(namespaced_event) => namespaced_event,
+            Err(error) => {
+                console::error_1(&error.into());
+                return;
+            }
+        };
 
         let mut hashmap = self.event_handlers.deref().borrow_mut();
 
-        if hashmap.contains_key(event_name) {
-            let handler_vec = hashmap.get_mut(event_name).unwrap();
+        for (handler_event_name, handler_vec) in hashmap.iter_mut() {
+            if !event_name.is_empty() && event_name != handler_event_name {
+                continue;
+            }
 
             match namespace {
                 Some(namespace) => {
-                    // remove all elements containing the namespace
+                    // remove all elements within the namespace
                     handler_vec.retain(|handler| {
                         if let Some(handler_namespace) = &handler.namespace {
-                            return handler_namespace != namespace;
+                            return !PlayerEvents::is_in_namespace(handler_namespace, namespace);
                         }
 
                         true
@@ -343,35 +864,616 @@ impl YtPlayer {
         }
     }
 
+    /// Remove a single handler by the ID returned from `on` or `once`.
+    #[wasm_bindgen(js_name = offId)]
+    pub fn off_id(&self, handler_id: u32) -> bool {
+        let mut removed = false;
+
+        for handler_vec in self.event_handlers.deref().borrow_mut().values_mut() {
+            let handler_count = handler_vec.len();
+            handler_vec.retain(|handler| handler.id != handler_id);
+
+            removed |= handler_vec.len() != handler_count;
+        }
+
+        removed
+    }
+
+    /// Remove the player iframe and release all event handlers, the player can't be used afterwards.
+    pub fn destroy(&mut self) {
+        *self.is_ready.deref().borrow_mut() = false;
+
+        self.time_update_poller.borrow_mut().stop();
+        self.monitor_poller.borrow_mut().stop();
+        self.resize.borrow_mut().binding = None;
+
+        let player_instance = self.player_instance.borrow_mut().take();
+
+        if let Some(instance) = player_instance {
+            instance.destroy();
+        }
+
+        self.command_queue.borrow_mut().clear();
+        self.event_handlers.deref().borrow_mut().clear();
+        self.closures.deref().borrow_mut().clear();
+    }
+
     #[wasm_bindgen(js_name = playVideo)]
-    pub fn play_video(&self) {
-        self.run_player(|instance| instance.play_video());
+    pub fn play_video(&self) -> Result<(), CommandError> {
+        self.run_player(|instance| instance.play_video())
     }
 
     #[wasm_bindgen(js_name = pauseVideo)]
-    pub fn pause_video(&self) {
-        self.run_player(|instance| instance.pause_video());
+    pub fn pause_video(&self) -> Result<(), CommandError> {
+        self.run_player(|instance| instance.pause_video())
     }
 
     #[wasm_bindgen(js_name = stopVideo)]
-    pub fn stop_video(&self) {
-        self.run_player(|instance| instance.stop_video());
+    pub fn stop_video(&self) -> Result<(), CommandError> {
+        self.run_player(|instance| instance.stop_video())
     }
 
     #[wasm_bindgen(js_name = changeVideo)]
-    pub fn change_video(&self, video_id: &str) {
-        self.run_player(|instance| instance.cue_video_by_id(video_id.into()));
+    pub fn change_video(&self, video_id: &str) -> Result<(), CommandError> {
+        self.cue_video(&VideoRequest::by_id(video_id.to_owned()))
+    }
+
+    #[wasm_bindgen(js_name = loadVideo)]
+    pub fn js_load_video(&self, request: VideoRequestObject) -> Result<(), CommandError> {
+        let request = VideoRequest::try_from(JsValue::from(request))
+            .map_err(|_| CommandError::InvalidRequest)?;
+
+        self.load_video(&request)
+    }
+
+    #[wasm_bindgen(js_name = cueVideo)]
+    pub fn js_cue_video(&self, request: VideoRequestObject) -> Result<(), CommandError> {
+        let request = VideoRequest::try_from(JsValue::from(request))
+            .map_err(|_| CommandError::InvalidRequest)?;
+
+        self.cue_video(&request)
     }
 
     #[wasm_bindgen(js_name = getPlayerState)]
-    pub fn get_player_state(&self) -> i32 {
-        let player_instance_option = self.get_player_instance();
+    pub fn js_get_player_state(&self) -> i32 {
+        self.get_player_state().value()
+    }
+
+    #[wasm_bindgen(js_name = seekTo)]
+    pub fn seek_to(&self, seconds: f64, allow_seek_ahead: bool) -> Result<(), CommandError> {
+        self.run_player(move |instance| instance.seek_to(seconds, allow_seek_ahead))
+    }
+
+    /// Elapsed time in seconds since the video started playing, `0` if the player isn't ready.
+    #[wasm_bindgen(js_name = getCurrentTime)]
+    pub fn current_time(&self) -> f64 {
+        self.get_player_instance()
+            .map(|instance| instance.get_current_time())
+            .unwrap_or(0.0)
+    }
+
+    /// Duration of the current video in seconds, `0` until the video metadata has been loaded.
+    #[wasm_bindgen(js_name = getDuration)]
+    pub fn duration(&self) -> f64 {
+        self.get_player_instance()
+            .map(|instance| instance.get_duration())
+            .unwrap_or(0.0)
+    }
+
+    /// Fraction between `0` and `1` of the video that the player has already buffered.
+    #[wasm_bindgen(js_name = getVideoLoadedFraction)]
+    pub fn video_loaded_fraction(&self) -> f64 {
+        self.get_player_instance()
+            .map(|instance| instance.get_video_loaded_fraction())
+            .unwrap_or(0.0)
+    }
+
+    /// Set volume between `0` and `100`, values outside of this range are clamped.
+    #[wasm_bindgen(js_name = setVolume)]
+    pub fn js_set_volume(&self, volume: f64) -> Result<(), CommandError> {
+        self.set_volume(volume.into())
+    }
+
+    #[wasm_bindgen(js_name = getVolume)]
+    pub fn js_volume(&self) -> u8 {
+        self.volume().value()
+    }
+
+    pub fn mute(&self) -> Result<(), CommandError> {
+        let handlers = self.event_handlers.clone();
+
+        self.run_player(move |instance| {
+            instance.mute();
+
+            Self::emit_volume_change(
+                &handlers,
+                VolumeChange {
+                    volume: instance.get_volume().into(),
+                    muted: true,
+                },
+            );
+        })
+    }
+
+    #[wasm_bindgen(js_name = unMute)]
+    pub fn un_mute(&self) -> Result<(), CommandError> {
+        let handlers = self.event_handlers.clone();
 
-        if let Some(instance) = player_instance_option {
-            let state = instance.get_player_state();
-            return from_value(state).unwrap();
+        self.run_player(move |instance| {
+            instance.un_mute();
+
+            Self::emit_volume_change(
+                &handlers,
+                VolumeChange {
+                    volume: instance.get_volume().into(),
+                    muted: false,
+                },
+            );
+        })
+    }
+
+    #[wasm_bindgen(js_name = isMuted)]
+    pub fn is_muted(&self) -> bool {
+        self.get_player_instance()
+            .map(|instance| instance.is_muted())
+            .unwrap_or(false)
+    }
+
+    #[wasm_bindgen(js_name = cuePlaylist)]
+    pub fn js_cue_playlist(&self, request: PlaylistRequestObject) -> Result<(), CommandError> {
+        let request = PlaylistRequest::try_from(JsValue::from(request))
+            .map_err(|_| CommandError::InvalidRequest)?;
+
+        self.cue_playlist(&request)
+    }
+
+    #[wasm_bindgen(js_name = loadPlaylist)]
+    pub fn js_load_playlist(&self, request: PlaylistRequestObject) -> Result<(), CommandError> {
+        let request = PlaylistRequest::try_from(JsValue::from(request))
+            .map_err(|_| CommandError::InvalidRequest)?;
+
+        self.load_playlist(&request)
+    }
+
+    #[wasm_bindgen(js_name = nextVideo)]
+    pub fn next_video(&self) -> Result<(), CommandError> {
+        self.run_player(|instance| instance.next_video())
+    }
+
+    #[wasm_bindgen(js_name = previousVideo)]
+    pub fn previous_video(&self) -> Result<(), CommandError> {
+        self.run_player(|instance| instance.previous_video())
+    }
+
+    #[wasm_bindgen(js_name = playVideoAt)]
+    pub fn play_video_at(&self, index: u32) -> Result<(), CommandError> {
+        self.run_player(move |instance| instance.play_video_at(index))
+    }
+
+    #[wasm_bindgen(js_name = setShuffle)]
+    pub fn set_shuffle(&self, shuffle_playlist: bool) -> Result<(), CommandError> {
+        self.run_player(move |instance| instance.set_shuffle(shuffle_playlist))
+    }
+
+    #[wasm_bindgen(js_name = setLoop)]
+    pub fn set_loop(&self, loop_playlists: bool) -> Result<(), CommandError> {
+        self.run_player(move |instance| instance.set_loop(loop_playlists))
+    }
+
+    #[wasm_bindgen(js_name = getPlaylist)]
+    pub fn js_playlist(&self) -> Array {
+        self.playlist().into_iter().map(JsValue::from).collect()
+    }
+
+    /// Index of the currently playing video, `undefined` if no playlist is loaded.
+    #[wasm_bindgen(js_name = getPlaylistIndex)]
+    pub fn playlist_index(&self) -> Option<u32> {
+        self.get_player_instance().and_then(|instance| {
+            let index = instance.get_playlist_index().as_f64()?;
+
+            if index < 0.0 {
+                return None;
+            }
+
+            Some(index as u32)
+        })
+    }
+
+    #[wasm_bindgen(js_name = setPlaybackRate)]
+    pub fn set_playback_rate(&self, suggested_rate: f64) -> Result<(), CommandError> {
+        self.run_player(move |instance| instance.set_playback_rate(suggested_rate))
+    }
+
+    /// Playback rate of the current video, `1` (normal speed) if the player isn't ready.
+    #[wasm_bindgen(js_name = getPlaybackRate)]
+    pub fn playback_rate(&self) -> f64 {
+        self.get_player_instance()
+            .map(|instance| instance.get_playback_rate())
+            .unwrap_or(1.0)
+    }
+
+    #[wasm_bindgen(js_name = getAvailablePlaybackRates)]
+    pub fn available_playback_rates(&self) -> Vec<f64> {
+        self.get_player_instance()
+            .and_then(|instance| from_value(instance.get_available_playback_rates()).ok())
+            .unwrap_or_default()
+    }
+
+    #[wasm_bindgen(js_name = setPlaybackQuality)]
+    pub fn js_set_playback_quality(&self, suggested_quality: &str) -> Result<(), CommandError> {
+        self.set_playback_quality(suggested_quality.into())
+    }
+
+    #[wasm_bindgen(js_name = getPlaybackQuality)]
+    pub fn js_playback_quality(&self) -> String {
+        self.playback_quality().into()
+    }
+
+    #[wasm_bindgen(js_name = getAvailableQualityLevels)]
+    pub fn js_available_quality_levels(&self) -> Array {
+        self.available_quality_levels()
+            .into_iter()
+            .map(|quality| JsValue::from(String::from(quality)))
+            .collect()
+    }
+
+    #[wasm_bindgen(js_name = snapshot)]
+    pub fn js_snapshot(&self) -> Result<PlayerSnapshotObject, JsValue> {
+        let snapshot = JsValue::try_from(&self.snapshot())?;
+
+        Ok(snapshot.unchecked_into())
+    }
+
+    #[wasm_bindgen(js_name = restore)]
+    pub fn js_restore(&self, snapshot: PlayerSnapshotObject) -> Result<(), CommandError> {
+        let snapshot = PlayerSnapshot::try_from(JsValue::from(snapshot))
+            .map_err(|_| CommandError::InvalidRequest)?;
+
+        self.restore(&snapshot)
+    }
+}
+
+impl YtPlayer {
+    /// Load the Youtube API if necessary and wait until the created player is ready.
+    ///
+    /// Target is the ID of an element or the element itself, like `new` and `from_element`.
+    pub fn create_async<T>(
+        target: T,
+        options: PlayerOptions,
+    ) -> impl Future<Output = Result<YtPlayer, PlayerInitError>>
+    where
+        T: Into<PlayerTarget>,
+    {
+        Self::create_instance(target.into(), options.into())
+    }
+
+    async fn create_instance(
+        target: PlayerTarget,
+        options: Object,
+    ) -> Result<Self, PlayerInitError> {
+        load_yt_api().wait().await?;
+
+        let instance = match target {
+            PlayerTarget::Id(player_id) => Self::new(&player_id, options),
+            PlayerTarget::Element(element) => Self::from_element(&element, options),
+        };
+        instance.player_loaded.wait().await?;
+
+        Ok(instance)
+    }
+
+    /// Register a handler receiving typed events, `event_name` may contain a namespace like in `on`.
+    pub fn on_event<F>(&self, event_name: &str, handler: F) -> Option<u32>
+    where
+        F: FnMut(PlayerEvent) + 'static,
+    {
+        let handler_fn = Self::create_typed_handler(event_name, handler);
+        self.register_handler(event_name, handler_fn, false)
+    }
+
+    /// Same as `on_event`, but the handler is removed after it ran once.
+    pub fn once_event<F>(&self, event_name: &str, handler: F) -> Option<u32>
+    where
+        F: FnMut(PlayerEvent) + 'static,
+    {
+        let handler_fn = Self::create_typed_handler(event_name, handler);
+        self.register_handler(event_name, handler_fn, true)
+    }
+
+    /// Closure of the typed handler is released with the handler by `off`, `off_id`
+    /// or after a `once` handler ran, instead of being kept until `destroy`.
+    fn create_typed_handler<F>(event_name: &str, mut handler: F) -> HandlerFunction
+    where
+        F: FnMut(PlayerEvent) + 'static,
+    {
+        let handler_event_name = event_name.to_owned();
+
+        let mut handle_event = move |event_name: &str, event: JsValue| match PlayerEvent::from_js(
+            event_name, &event,
+        ) {
+            Ok(player_event) => handler(player_event),
+            Err(error) => console::error_1(&error.into()),
+        };
+
+        if event_name.split('.').next() == Some(PlayerEvents::WILDCARD) {
+            // wildcard handlers receive the event name as first parameter
+            let handler_fn = Closure::wrap(Box::new(move |event_name: JsValue, event: JsValue| {
+                if let Some(event_name) = event_name.as_string() {
+                    handle_event(&event_name, event);
+                }
+            }) as Box<dyn FnMut(JsValue, JsValue)>);
+
+            return HandlerFunction::from_closure(handler_fn);
         }
 
-        PlayerState::UNSTARTED
+        let handler_fn = Closure::wrap(Box::new(move |event: JsValue| {
+            handle_event(&handler_event_name, event);
+        }) as Box<dyn FnMut(JsValue)>);
+
+        HandlerFunction::from_closure(handler_fn)
+    }
+
+    /// Current state of the player, `PlayerState::Unstarted` if the player isn't ready.
+    pub fn get_player_state(&self) -> PlayerState {
+        self.get_player_instance()
+            .and_then(|instance| from_value(instance.get_player_state()).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn set_volume(&self, volume: Volume) -> Result<(), CommandError> {
+        let handlers = self.event_handlers.clone();
+
+        self.run_player(move |instance| {
+            instance.set_volume(volume.value());
+
+            Self::emit_volume_change(
+                &handlers,
+                VolumeChange {
+                    volume,
+                    muted: instance.is_muted(),
+                },
+            );
+        })
+    }
+
+    pub fn volume(&self) -> Volume {
+        self.get_player_instance()
+            .map(|instance| instance.get_volume().into())
+            .unwrap_or_default()
+    }
+
+    /// Load and play the requested video.
+    pub fn load_video(&self, request: &VideoRequest) -> Result<(), CommandError> {
+        self.run_player(Self::start_video(request, true)?)
+    }
+
+    /// Load the requested video without playing it until `play_video` is called.
+    pub fn cue_video(&self, request: &VideoRequest) -> Result<(), CommandError> {
+        self.run_player(Self::start_video(request, false)?)
+    }
+
+    pub fn cue_playlist(&self, request: &PlaylistRequest) -> Result<(), CommandError> {
+        self.run_player(Self::start_playlist(request, false)?)
+    }
+
+    pub fn load_playlist(&self, request: &PlaylistRequest) -> Result<(), CommandError> {
+        self.run_player(Self::start_playlist(request, true)?)
+    }
+
+    /// Command loading (`play`) or cueing the requested video, which is serialized up front,
+    /// so a failure is returned to the caller instead of being lost in the queue.
+    fn start_video(
+        request: &VideoRequest,
+        play: bool,
+    ) -> Result<Command<PlayerInstance>, CommandError> {
+        let video = JsValue::try_from(request).map_err(|_| CommandError::InvalidRequest)?;
+        let by_url = matches!(request.source, VideoSource::Url { .. });
+
+        Ok(Box::new(move |instance: &PlayerInstance| {
+            match (by_url, play) {
+                (false, true) => instance.load_video_by_id(video),
+                (false, false) => instance.cue_video_by_id(video),
+                (true, true) => instance.load_video_by_url(video),
+                (true, false) => instance.cue_video_by_url(video),
+            }
+        }))
+    }
+
+    /// Command loading (`play`) or cueing the requested playlist, which is serialized up front
+    /// like in `start_video`.
+    fn start_playlist(
+        request: &PlaylistRequest,
+        play: bool,
+    ) -> Result<Command<PlayerInstance>, CommandError> {
+        match &request.source {
+            PlaylistSource::Videos { playlist } => {
+                let playlist: Array = playlist.iter().map(JsValue::from).collect();
+                let (index, start_seconds) = (request.index, request.start_seconds);
+
+                Ok(Box::new(move |instance: &PlayerInstance| match play {
+                    true => instance.load_playlist(playlist, index, start_seconds),
+                    false => instance.cue_playlist(playlist, index, start_seconds),
+                }))
+            }
+            PlaylistSource::List { .. } => {
+                let list = JsValue::try_from(request).map_err(|_| CommandError::InvalidRequest)?;
+
+                Ok(Box::new(move |instance: &PlayerInstance| match play {
+                    true => instance.load_playlist_by_object(list),
+                    false => instance.cue_playlist_by_object(list),
+                }))
+            }
+        }
+    }
+
+    /// Video IDs of the current playlist, empty if no playlist is loaded.
+    pub fn playlist(&self) -> Vec<String> {
+        self.get_player_instance()
+            .and_then(|instance| from_value::<Option<Vec<String>>>(instance.get_playlist()).ok())
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Only a suggestion, the player may choose a different quality.
+    pub fn set_playback_quality(
+        &self,
+        suggested_quality: PlaybackQuality,
+    ) -> Result<(), CommandError> {
+        self.run_player(move |instance| {
+            instance.set_playback_quality(suggested_quality.as_str().into())
+        })
+    }
+
+    pub fn playback_quality(&self) -> PlaybackQuality {
+        self.get_player_instance()
+            .and_then(|instance| from_value(instance.get_playback_quality()).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn available_quality_levels(&self) -> Vec<PlaybackQuality> {
+        self.get_player_instance()
+            .and_then(|instance| from_value(instance.get_available_quality_levels()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Capture video, position and settings of the player, apply them again with `restore`.
+    ///
+    /// Returns the default snapshot without video if the player isn't ready.
+    pub fn snapshot(&self) -> PlayerSnapshot {
+        let instance = match self.get_player_instance() {
+            Some(instance) => instance,
+            None => return PlayerSnapshot::default(),
+        };
+
+        // unlike parsing `getVideoUrl`, this doesn't fail for start times the parser rejects
+        let video_id = Reflect::get(&instance.get_video_data(), &"video_id".into())
+            .ok()
+            .and_then(|video_id| video_id.as_string())
+            .filter(|video_id| !video_id.is_empty());
+
+        PlayerSnapshot {
+            video_id,
+            current_time: self.current_time(),
+            state: self.get_player_state(),
+            playback_rate: self.playback_rate(),
+            volume: self.volume(),
+            muted: self.is_muted(),
+            playback_quality: self.playback_quality(),
+            playlist: self.playlist(),
+            playlist_index: self.playlist_index(),
+        }
+    }
+
+    /// Apply a snapshot once the player is ready, regardless of the `PendingCommandPolicy`.
+    ///
+    /// Video or playlist is loaded at the captured position if it was playing, cued otherwise.
+    pub fn restore(&self, snapshot: &PlayerSnapshot) -> Result<(), CommandError> {
+        let play = snapshot.is_playing();
+        let start = match (snapshot.playlist_request(), snapshot.video_request()) {
+            (Some(request), _) => Some(Self::start_playlist(&request, play)?),
+            (None, Some(request)) => Some(Self::start_video(&request, play)?),
+            (None, None) => None,
+        };
+
+        let snapshot = snapshot.clone();
+        let handlers = self.event_handlers.clone();
+
+        self.run_when_ready(move |instance| {
+            if let Some(start) = start {
+                start(instance);
+            }
+
+            instance.set_volume(snapshot.volume.value());
+
+            match snapshot.muted {
+                true => instance.mute(),
+                false => instance.un_mute(),
+            }
+
+            instance.set_playback_rate(snapshot.playback_rate);
+            instance.set_playback_quality(snapshot.playback_quality.as_str().into());
+
+            Self::emit_volume_change(
+                &handlers,
+                VolumeChange {
+                    volume: snapshot.volume,
+                    muted: snapshot.muted,
+                },
+            );
+        });
+
+        Ok(())
+    }
+
+    /// Emit `seeked` and `stalled` events with the given thresholds, `None` stops the events.
+    pub fn set_playback_monitor(&self, options: Option<MonitorOptions>) {
+        let mut poller = self.monitor_poller.borrow_mut();
+
+        match options.filter(|options| options.interval > 0) {
+            Some(options) => poller.start(
+                options.interval,
+                Self::create_monitor_poll(
+                    options,
+                    self.is_ready.clone(),
+                    self.player_instance.clone(),
+                    self.event_handlers.clone(),
+                ),
+            ),
+            None => poller.stop(),
+        }
+    }
+
+    fn create_monitor_poll(
+        options: MonitorOptions,
+        is_ready: Rc<RefCell<bool>>,
+        player_instance: SharedPlayerInstance,
+        handler_hashmap: EventHandlerHashmap,
+    ) -> Box<dyn FnMut()> {
+        let mut monitor = PlaybackMonitor::new(options);
+
+        Box::new(move || {
+            if !*is_ready.borrow() {
+                return;
+            }
+
+            let instance = match player_instance.borrow().clone() {
+                Some(instance) => instance,
+                None => return,
+            };
+
+            let events = monitor.sample(
+                Date::now(),
+                from_value(instance.get_player_state()).unwrap_or_default(),
+                instance.get_current_time(),
+                instance.get_playback_rate(),
+            );
+
+            for event in events {
+                let (event_name, data) = match event {
+                    MonitorEvent::Seeked(seek) => (PlayerEvents::SEEKED, to_value(&seek)),
+                    MonitorEvent::Stalled(stall) => (PlayerEvents::STALLED, to_value(&stall)),
+                };
+
+                match data {
+                    Ok(data) => Self::dispatch_synthetic_event(&handler_hashmap, event_name, data),
+                    Err(error) => console::error_1(&error.into()),
+                }
+            }
+        })
+    }
+
+    fn emit_volume_change(handler_hashmap: &EventHandlerHashmap, volume_change: VolumeChange) {
+        match to_value(&volume_change) {
+            Ok(data) => {
+                Self::dispatch_synthetic_event(handler_hashmap, PlayerEvents::VOLUME_CHANGE, data)
+            }
+            Err(error) => console::error_1(&error.into()),
+        }
+    }
+}
+
+impl Drop for YtPlayer {
+    fn drop(&mut self) {
+        self.destroy();
     }
 }
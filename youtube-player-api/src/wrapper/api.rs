@@ -1,13 +1,26 @@
-use js_sys::JsString;
+use js_sys::{Array, Function, JsString};
 use wasm_bindgen::prelude::*;
+use web_sys::Element;
 
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(typescript_type = "YtGlobal")]
     pub type YtGlobalObject;
 
+    #[wasm_bindgen(typescript_type = "LoaderOptions")]
+    pub type LoaderOptionsObject;
+
+    #[wasm_bindgen(typescript_type = "PlayerSnapshot")]
+    pub type PlayerSnapshotObject;
+
+    #[wasm_bindgen(typescript_type = "PlaylistRequest")]
+    pub type PlaylistRequestObject;
+
+    #[wasm_bindgen(typescript_type = "VideoRequest")]
+    pub type VideoRequestObject;
+
     #[wasm_bindgen(typescript_type = "YoutubePlayerInstance")]
-    #[derive(Debug)]
+    #[derive(Clone, Debug)]
     pub type PlayerInstance;
 
     #[wasm_bindgen(method, js_name = addEventListener)]
@@ -18,6 +31,9 @@ extern "C" {
     // #[wasm_bindgen(method, js_name = removeEventListener)]
     // pub fn remove_event_listener(this: &PlayerInstance, event: JsString, listener: JsValue);
 
+    #[wasm_bindgen(method)]
+    pub fn destroy(this: &PlayerInstance);
+
     #[wasm_bindgen(method, js_name = playVideo)]
     pub fn play_video(this: &PlayerInstance);
 
@@ -28,55 +44,141 @@ extern "C" {
     pub fn stop_video(this: &PlayerInstance);
 
     #[wasm_bindgen(method, js_name = cueVideoById)]
-    pub fn cue_video_by_id(this: &PlayerInstance, video_id: JsString);
+    pub fn cue_video_by_id(this: &PlayerInstance, video: JsValue);
+
+    #[wasm_bindgen(method, js_name = loadVideoById)]
+    pub fn load_video_by_id(this: &PlayerInstance, video: JsValue);
+
+    #[wasm_bindgen(method, js_name = cueVideoByUrl)]
+    pub fn cue_video_by_url(this: &PlayerInstance, video: JsValue);
+
+    #[wasm_bindgen(method, js_name = loadVideoByUrl)]
+    pub fn load_video_by_url(this: &PlayerInstance, video: JsValue);
 
     #[wasm_bindgen(method, js_name = getPlayerState)]
     pub fn get_player_state(this: &PlayerInstance) -> JsValue;
-}
 
-#[wasm_bindgen(typescript_custom_section)]
-const PLAYER_STATE: &'static str = r#"
-export const enum PlayerState {
-  UNSTARTED = -1,
-  ENDED = 0,
-  PLAYING = 1,
-  PAUSED = 2,
-  BUFFERING = 3,
-  CUED = 5,
-}
-"#;
+    #[wasm_bindgen(method, js_name = seekTo)]
+    pub fn seek_to(this: &PlayerInstance, seconds: f64, allow_seek_ahead: bool);
 
-#[wasm_bindgen(typescript_custom_section)]
-const PLAYER_VARS: &'static str = r#"
-export interface PlayerVars {
-  autoplay?: 0 | 1;
-  controls?: 0 | 1;
+    #[wasm_bindgen(method, js_name = getCurrentTime)]
+    pub fn get_current_time(this: &PlayerInstance) -> f64;
+
+    #[wasm_bindgen(method, js_name = getDuration)]
+    pub fn get_duration(this: &PlayerInstance) -> f64;
+
+    #[wasm_bindgen(method, js_name = getVideoLoadedFraction)]
+    pub fn get_video_loaded_fraction(this: &PlayerInstance) -> f64;
+
+    #[wasm_bindgen(method, js_name = setVolume)]
+    pub fn set_volume(this: &PlayerInstance, volume: u8);
+
+    #[wasm_bindgen(method, js_name = getVolume)]
+    pub fn get_volume(this: &PlayerInstance) -> f64;
+
+    #[wasm_bindgen(method)]
+    pub fn mute(this: &PlayerInstance);
+
+    #[wasm_bindgen(method, js_name = unMute)]
+    pub fn un_mute(this: &PlayerInstance);
+
+    #[wasm_bindgen(method, js_name = isMuted)]
+    pub fn is_muted(this: &PlayerInstance) -> bool;
+
+    #[wasm_bindgen(method, js_name = cuePlaylist)]
+    pub fn cue_playlist(
+        this: &PlayerInstance,
+        playlist: Array,
+        index: Option<u32>,
+        start_seconds: Option<f64>,
+    );
+
+    #[wasm_bindgen(method, js_name = cuePlaylist)]
+    pub fn cue_playlist_by_object(this: &PlayerInstance, playlist: JsValue);
+
+    #[wasm_bindgen(method, js_name = loadPlaylist)]
+    pub fn load_playlist(
+        this: &PlayerInstance,
+        playlist: Array,
+        index: Option<u32>,
+        start_seconds: Option<f64>,
+    );
+
+    #[wasm_bindgen(method, js_name = loadPlaylist)]
+    pub fn load_playlist_by_object(this: &PlayerInstance, playlist: JsValue);
+
+    #[wasm_bindgen(method, js_name = nextVideo)]
+    pub fn next_video(this: &PlayerInstance);
+
+    #[wasm_bindgen(method, js_name = previousVideo)]
+    pub fn previous_video(this: &PlayerInstance);
+
+    #[wasm_bindgen(method, js_name = playVideoAt)]
+    pub fn play_video_at(this: &PlayerInstance, index: u32);
+
+    #[wasm_bindgen(method, js_name = setShuffle)]
+    pub fn set_shuffle(this: &PlayerInstance, shuffle_playlist: bool);
+
+    #[wasm_bindgen(method, js_name = setLoop)]
+    pub fn set_loop(this: &PlayerInstance, loop_playlists: bool);
+
+    #[wasm_bindgen(method, js_name = getPlaylist)]
+    pub fn get_playlist(this: &PlayerInstance) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = getPlaylistIndex)]
+    pub fn get_playlist_index(this: &PlayerInstance) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = setPlaybackRate)]
+    pub fn set_playback_rate(this: &PlayerInstance, suggested_rate: f64);
+
+    #[wasm_bindgen(method, js_name = getPlaybackRate)]
+    pub fn get_playback_rate(this: &PlayerInstance) -> f64;
+
+    /// Undocumented, but the only getter of the loaded video ID.
+    #[wasm_bindgen(method, js_name = getVideoData)]
+    pub fn get_video_data(this: &PlayerInstance) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = getAvailablePlaybackRates)]
+    pub fn get_available_playback_rates(this: &PlayerInstance) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = setPlaybackQuality)]
+    pub fn set_playback_quality(this: &PlayerInstance, suggested_quality: JsString);
+
+    #[wasm_bindgen(method, js_name = getPlaybackQuality)]
+    pub fn get_playback_quality(this: &PlayerInstance) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = getAvailableQualityLevels)]
+    pub fn get_available_quality_levels(this: &PlayerInstance) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = getIframe)]
+    pub fn get_iframe(this: &PlayerInstance) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = setSize)]
+    pub fn set_size(this: &PlayerInstance, width: f64, height: f64);
+
+    // not part of all supported web-sys versions, so it's bound here
+    #[derive(Debug)]
+    pub type ResizeObserver;
+
+    #[wasm_bindgen(constructor, catch)]
+    pub fn new(callback: &Function) -> Result<ResizeObserver, JsValue>;
+
+    #[wasm_bindgen(method)]
+    pub fn observe(this: &ResizeObserver, target: &Element);
+
+    #[wasm_bindgen(method)]
+    pub fn disconnect(this: &ResizeObserver);
 }
-"#;
 
+// declarations of types deriving `TypeScript` are emitted by the derive,
+// interfaces are merged with their generated declaration
 #[wasm_bindgen(typescript_custom_section)]
 const PLAYER_OPTIONS: &'static str = r#"
 export interface PlayerOptions {
-  videoId?: string;
-  width?: number;
-  height?: number;
-  playerVars?: PlayerVars;
   events?: PlayerEvents;
 }
 "#;
 
-#[wasm_bindgen(typescript_custom_section)]
-const PLAYER_EVENT_NAME: &'static str = r#"
-export const enum PlayerEventName {
-  READY = 'ready',
-  ERROR = 'error',
-  STATE_CHANGE = 'stateChange',
-  PLAYBACK_QUALITY_CHANGE = 'playbackQualityChange',
-  PLAYBACK_RATE_CHANGE = 'playbackRateChange',
-  API_CHANGE = 'apiChange',
-}
-"#;
-
 #[wasm_bindgen(typescript_custom_section)]
 const PLAYER_EVENTS: &'static str = r#"
 export interface PlayerEvents {
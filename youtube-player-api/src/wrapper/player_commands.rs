@@ -0,0 +1,88 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use crate::TypeScript;
+
+/// Handling of player commands (e.g. `play_video`) issued before the player is ready.
+///
+/// Commands of a player which couldn't be constructed or was destroyed always return
+/// `CommandError::Unavailable`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, TypeScript)]
+#[serde(rename_all = "lowercase")]
+pub enum PendingCommandPolicy {
+    /// Discard the command with a warning.
+    Drop,
+    /// Buffer the command and replay it in order as soon as the player is ready.
+    #[default]
+    Queue,
+    /// Reject the command, the command returns `CommandError::NotReady` (throws in JS).
+    Error,
+}
+
+pub(crate) type Command<T> = Box<dyn FnOnce(&T)>;
+
+/// Commands buffered until the player is ready.
+pub(crate) struct CommandQueue<T> {
+    commands: Vec<Command<T>>,
+}
+
+impl<T> CommandQueue<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            commands: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, command: Command<T>) {
+        self.commands.push(command);
+    }
+
+    /// Remove all commands, so they can be replayed without holding a borrow on the queue.
+    pub(crate) fn take(&mut self) -> Vec<Command<T>> {
+        core::mem::take(&mut self.commands)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.commands.clear();
+    }
+}
+
+impl<T> fmt::Debug for CommandQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandQueue")
+            .field("commands", &self.commands.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec;
+    use core::cell::RefCell;
+
+    #[test]
+    fn pending_command_policy_default() {
+        assert_eq!(PendingCommandPolicy::Queue, PendingCommandPolicy::default());
+    }
+
+    #[test]
+    fn command_queue_replay_in_order() {
+        let mut queue: CommandQueue<RefCell<Vec<u32>>> = CommandQueue::new();
+
+        queue.push(Box::new(|calls| calls.borrow_mut().push(1)));
+        queue.push(Box::new(|calls| calls.borrow_mut().push(2)));
+        queue.push(Box::new(|calls| calls.borrow_mut().push(3)));
+
+        let calls = RefCell::new(vec![]);
+
+        for command in queue.take() {
+            command(&calls);
+        }
+
+        assert_eq!(vec![1, 2, 3], calls.into_inner());
+        assert!(queue.take().is_empty());
+    }
+}
@@ -0,0 +1,151 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Error codes provided by the `error` event, see <https://developers.google.com/youtube/iframe_api_reference#onError>.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum PlayerError {
+    /// Request contains an invalid parameter value, e.g. a malformed video ID.
+    InvalidParam,
+    /// Content can't be played in an HTML5 player.
+    Html5Error,
+    /// Video was removed or marked as private.
+    NotFound,
+    /// Owner of the video doesn't allow playback in embedded players.
+    EmbeddingDisallowed,
+    /// Same as `EmbeddingDisallowed`, but reported with a different error code.
+    EmbeddingDisallowedDisguised,
+    /// Error code not (yet) known by this library.
+    Other(i32),
+}
+
+impl PlayerError {
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::InvalidParam => 2,
+            Self::Html5Error => 5,
+            Self::NotFound => 100,
+            Self::EmbeddingDisallowed => 101,
+            Self::EmbeddingDisallowedDisguised => 150,
+            Self::Other(code) => *code,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::InvalidParam => "The request contains an invalid parameter value.",
+            Self::Html5Error => {
+                "The requested content can't be played in an HTML5 player or another HTML5 related error occurred."
+            }
+            Self::NotFound => "The requested video was not found, it was removed or marked as private.",
+            Self::EmbeddingDisallowed | Self::EmbeddingDisallowedDisguised => {
+                "The owner of the requested video doesn't allow playback in embedded players."
+            }
+            Self::Other(_) => "An unknown error occurred.",
+        }
+    }
+
+    /// Error is caused by the requested video itself, requesting it again won't help.
+    pub fn is_video_unavailable(&self) -> bool {
+        matches!(
+            self,
+            Self::NotFound | Self::EmbeddingDisallowed | Self::EmbeddingDisallowedDisguised
+        )
+    }
+
+    pub fn is_embedding_disallowed(&self) -> bool {
+        matches!(
+            self,
+            Self::EmbeddingDisallowed | Self::EmbeddingDisallowedDisguised
+        )
+    }
+
+    /// Player may be able to play the video when reloading or retrying it,
+    /// errors about invalid or unavailable videos are never recoverable.
+    pub fn is_recoverable(&self) -> bool {
+        !matches!(self, Self::InvalidParam) && !self.is_video_unavailable()
+    }
+}
+
+impl From<i32> for PlayerError {
+    fn from(code: i32) -> Self {
+        match code {
+            2 => Self::InvalidParam,
+            5 => Self::Html5Error,
+            100 => Self::NotFound,
+            101 => Self::EmbeddingDisallowed,
+            150 => Self::EmbeddingDisallowedDisguised,
+            code => Self::Other(code),
+        }
+    }
+}
+
+impl From<PlayerError> for i32 {
+    fn from(error: PlayerError) -> Self {
+        error.code()
+    }
+}
+
+impl fmt::Display for PlayerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (error code {})", self.description(), self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString;
+
+    #[test]
+    fn player_error_convert() {
+        for (error, code) in [
+            (PlayerError::InvalidParam, 2),
+            (PlayerError::Html5Error, 5),
+            (PlayerError::NotFound, 100),
+            (PlayerError::EmbeddingDisallowed, 101),
+            (PlayerError::EmbeddingDisallowedDisguised, 150),
+            (PlayerError::Other(42), 42),
+        ] {
+            assert_eq!(error, PlayerError::from(code));
+            assert_eq!(code, i32::from(error));
+        }
+    }
+
+    #[test]
+    fn player_error_classification() {
+        assert!(!PlayerError::InvalidParam.is_recoverable());
+        assert!(!PlayerError::InvalidParam.is_video_unavailable());
+
+        assert!(PlayerError::Html5Error.is_recoverable());
+        assert!(!PlayerError::Html5Error.is_video_unavailable());
+
+        assert!(!PlayerError::NotFound.is_recoverable());
+        assert!(PlayerError::NotFound.is_video_unavailable());
+        assert!(!PlayerError::NotFound.is_embedding_disallowed());
+
+        for error in [
+            PlayerError::EmbeddingDisallowed,
+            PlayerError::EmbeddingDisallowedDisguised,
+        ] {
+            assert!(!error.is_recoverable());
+            assert!(error.is_video_unavailable());
+            assert!(error.is_embedding_disallowed());
+        }
+
+        assert!(PlayerError::Other(42).is_recoverable());
+    }
+
+    #[test]
+    fn player_error_display() {
+        assert_eq!(
+            "The owner of the requested video doesn't allow playback in embedded players. (error code 150)",
+            PlayerError::from(150).to_string()
+        );
+        assert_eq!(
+            "An unknown error occurred. (error code 42)",
+            PlayerError::Other(42).to_string()
+        );
+    }
+}
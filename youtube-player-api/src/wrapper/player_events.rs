@@ -1,4 +1,66 @@
 use alloc::{borrow::ToOwned, string::String};
+use js_sys::Reflect;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_wasm_bindgen::from_value;
+use wasm_bindgen::JsValue;
+
+use super::{PlaybackQuality, PlayerError, PlayerState, Seek, Stall, VolumeChange};
+use crate::TypeScript;
+
+/// Names of the events in `PlayerEvents`, declared as TypeScript `const enum PlayerEventName`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TypeScript)]
+#[serde(rename_all = "camelCase")]
+#[ts(const_enum)]
+pub enum PlayerEventName {
+    Ready,
+    Error,
+    StateChange,
+    PlaybackQualityChange,
+    PlaybackRateChange,
+    ApiChange,
+    VolumeChange,
+    TimeUpdate,
+    Progress,
+    Seeked,
+    Stalled,
+    #[serde(rename = "*")]
+    Wildcard,
+}
+
+impl PlayerEventName {
+    pub const ALL: [Self; 12] = [
+        Self::Ready,
+        Self::Error,
+        Self::StateChange,
+        Self::PlaybackQualityChange,
+        Self::PlaybackRateChange,
+        Self::ApiChange,
+        Self::VolumeChange,
+        Self::TimeUpdate,
+        Self::Progress,
+        Self::Seeked,
+        Self::Stalled,
+        Self::Wildcard,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ready => PlayerEvents::READY,
+            Self::Error => PlayerEvents::ERROR,
+            Self::StateChange => PlayerEvents::STATE_CHANGE,
+            Self::PlaybackQualityChange => PlayerEvents::PLAYBACK_QUALITY_CHANGE,
+            Self::PlaybackRateChange => PlayerEvents::PLAYBACK_RATE_CHANGE,
+            Self::ApiChange => PlayerEvents::API_CHANGE,
+            Self::VolumeChange => PlayerEvents::VOLUME_CHANGE,
+            Self::TimeUpdate => PlayerEvents::TIME_UPDATE,
+            Self::Progress => PlayerEvents::PROGRESS,
+            Self::Seeked => PlayerEvents::SEEKED,
+            Self::Stalled => PlayerEvents::STALLED,
+            Self::Wildcard => PlayerEvents::WILDCARD,
+        }
+    }
+}
 
 // #[wasm_bindgen(typescript_type = "PlayerEvents")]
 // #[wasm_bindgen(js_name = PlayerEvents)]
@@ -15,6 +77,42 @@ impl PlayerEvents {
     pub const READY: &'static str = "ready";
     pub const STATE_CHANGE: &'static str = "stateChange";
 
+    // synthetic events, emitted by the wrapper itself instead of the Youtube API
+    pub const VOLUME_CHANGE: &'static str = "volumeChange";
+    /// Current time in seconds, emitted repeatedly while playing or buffering if enabled by `timeUpdateInterval`.
+    pub const TIME_UPDATE: &'static str = "timeUpdate";
+    /// Fraction of the video loaded by the player, emitted by the same poller as `timeUpdate` if it changed.
+    pub const PROGRESS: &'static str = "progress";
+    /// Playback position jumped, emitted if enabled by `playbackMonitor`.
+    pub const SEEKED: &'static str = "seeked";
+    /// Buffering lasted longer than the threshold set by `playbackMonitor`.
+    pub const STALLED: &'static str = "stalled";
+
+    /// Handlers for this event receive every event, called with parameters `(eventName, event?)`.
+    pub const WILDCARD: &'static str = "*";
+
+    /// All events provided by the Youtube API.
+    pub const API_EVENTS: [&'static str; 6] = [
+        Self::API_CHANGE,
+        Self::ERROR,
+        Self::PLAYBACK_QUALITY_CHANGE,
+        Self::PLAYBACK_RATE_CHANGE,
+        Self::READY,
+        Self::STATE_CHANGE,
+    ];
+
+    const SYNTHETIC_EVENTS: [&'static str; 5] = [
+        Self::VOLUME_CHANGE,
+        Self::TIME_UPDATE,
+        Self::PROGRESS,
+        Self::SEEKED,
+        Self::STALLED,
+    ];
+
+    pub fn is_synthetic(event_name: &str) -> bool {
+        Self::SYNTHETIC_EVENTS.contains(&event_name)
+    }
+
     pub fn get_handler_name(event_name: &str) -> Result<String, &'static str> {
         if event_name.is_empty(){
             return Err("Event name must not be empty!");
@@ -63,13 +161,88 @@ impl PlayerEvents {
 
         Ok(namespaced_event)
     }
+
+    /// Namespaces are hierarchical, `sync` contains `sync` itself and also `sync.drift`.
+    pub fn is_in_namespace(handler_namespace: &str, namespace: &str) -> bool {
+        match handler_namespace.strip_prefix(namespace) {
+            Some(rest) => rest.is_empty() || rest.starts_with('.'),
+            None => false,
+        }
+    }
+}
+
+/// Typed player event, passed to handlers registered by `YtPlayer::on_event`.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlayerEvent {
+    Ready,
+    StateChange(PlayerState),
+    Error(PlayerError),
+    QualityChange(PlaybackQuality),
+    RateChange(f64),
+    ApiChange,
+    VolumeChange(VolumeChange),
+    TimeUpdate(f64),
+    Progress(f64),
+    Seeked(Seek),
+    Stalled(Stall),
+}
+
+impl PlayerEvent {
+    /// Read typed event from event object provided to the event handlers, event data is expected in `event.data`.
+    pub fn from_js(event_name: &str, event: &JsValue) -> Result<Self, &'static str> {
+        let (event_name, _namespace) = PlayerEvents::get_namespaced_event(event_name)?;
+
+        let player_event = match event_name {
+            PlayerEvents::READY => Self::Ready,
+            PlayerEvents::API_CHANGE => Self::ApiChange,
+            PlayerEvents::STATE_CHANGE => Self::StateChange(Self::read_data(event)?),
+            PlayerEvents::ERROR => Self::Error(Self::read_data(event)?),
+            PlayerEvents::PLAYBACK_QUALITY_CHANGE => Self::QualityChange(Self::read_data(event)?),
+            PlayerEvents::PLAYBACK_RATE_CHANGE => Self::RateChange(Self::read_data(event)?),
+            PlayerEvents::VOLUME_CHANGE => Self::VolumeChange(Self::read_data(event)?),
+            PlayerEvents::TIME_UPDATE => Self::TimeUpdate(Self::read_data(event)?),
+            PlayerEvents::PROGRESS => Self::Progress(Self::read_data(event)?),
+            PlayerEvents::SEEKED => Self::Seeked(Self::read_data(event)?),
+            PlayerEvents::STALLED => Self::Stalled(Self::read_data(event)?),
+            _ => return Err("Unknown event name!"),
+        };
+
+        Ok(player_event)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ready => PlayerEvents::READY,
+            Self::StateChange(_) => PlayerEvents::STATE_CHANGE,
+            Self::Error(_) => PlayerEvents::ERROR,
+            Self::QualityChange(_) => PlayerEvents::PLAYBACK_QUALITY_CHANGE,
+            Self::RateChange(_) => PlayerEvents::PLAYBACK_RATE_CHANGE,
+            Self::ApiChange => PlayerEvents::API_CHANGE,
+            Self::VolumeChange(_) => PlayerEvents::VOLUME_CHANGE,
+            Self::TimeUpdate(_) => PlayerEvents::TIME_UPDATE,
+            Self::Progress(_) => PlayerEvents::PROGRESS,
+            Self::Seeked(_) => PlayerEvents::SEEKED,
+            Self::Stalled(_) => PlayerEvents::STALLED,
+        }
+    }
+
+    fn read_data<T: DeserializeOwned>(event: &JsValue) -> Result<T, &'static str> {
+        if event.is_undefined() || event.is_null() {
+            return Err("Event doesn't contain any data!");
+        }
+
+        let data = Reflect::get(event, &"data".into()).map_err(|_| "Can't read event data!")?;
+
+        from_value(data).map_err(|_| "Unexpected event data!")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use alloc::string::String;
+    use alloc::{string::String, vec::Vec};
     // use test::Bencher;
 
     #[test]
@@ -149,6 +322,58 @@ mod tests {
         assert_eq!(true, event_name.is_err());
     }
 
+    #[test]
+    fn is_synthetic() {
+        assert!(PlayerEvents::is_synthetic(PlayerEvents::VOLUME_CHANGE));
+        assert!(PlayerEvents::is_synthetic(PlayerEvents::TIME_UPDATE));
+        assert!(PlayerEvents::is_synthetic(PlayerEvents::PROGRESS));
+        assert!(PlayerEvents::is_synthetic(PlayerEvents::SEEKED));
+        assert!(PlayerEvents::is_synthetic(PlayerEvents::STALLED));
+
+        assert!(!PlayerEvents::is_synthetic(PlayerEvents::READY));
+        assert!(!PlayerEvents::is_synthetic(PlayerEvents::STATE_CHANGE));
+        assert!(!PlayerEvents::is_synthetic(""));
+    }
+
+    #[test]
+    fn player_event_name() {
+        for event in [
+            (PlayerEvent::Ready, PlayerEvents::READY),
+            (
+                PlayerEvent::StateChange(PlayerState::Playing),
+                PlayerEvents::STATE_CHANGE,
+            ),
+            (
+                PlayerEvent::Error(PlayerError::NotFound),
+                PlayerEvents::ERROR,
+            ),
+            (
+                PlayerEvent::QualityChange(PlaybackQuality::Hd720),
+                PlayerEvents::PLAYBACK_QUALITY_CHANGE,
+            ),
+            (
+                PlayerEvent::RateChange(1.5),
+                PlayerEvents::PLAYBACK_RATE_CHANGE,
+            ),
+            (PlayerEvent::ApiChange, PlayerEvents::API_CHANGE),
+            (PlayerEvent::TimeUpdate(12.5), PlayerEvents::TIME_UPDATE),
+            (PlayerEvent::Progress(0.5), PlayerEvents::PROGRESS),
+            (
+                PlayerEvent::Seeked(Seek {
+                    from: 0.0,
+                    to: 30.0,
+                }),
+                PlayerEvents::SEEKED,
+            ),
+            (
+                PlayerEvent::Stalled(Stall { duration: 3.0 }),
+                PlayerEvents::STALLED,
+            ),
+        ] {
+            assert_eq!(event.1, event.0.name());
+        }
+    }
+
     #[test]
     fn get_namespaced_event() {
         let namespace = "test.namespace";
@@ -160,7 +385,7 @@ mod tests {
         assert_eq!(PlayerEvents::STATE_CHANGE, event.0);
         assert_eq!(None, event.1);
 
-        // all after first dot is one namespace (no hierarchies)
+        // all after first dot is one namespace path (hierarchies are separated by dots)
         let event_name = PlayerEvents::STATE_CHANGE.to_owned() + "." + namespace;
         let namespaced_event = PlayerEvents::get_namespaced_event(&event_name).unwrap();
 
@@ -168,6 +393,20 @@ mod tests {
         assert_eq!(namespace, namespaced_event.1.unwrap());
     }
 
+    #[test]
+    fn is_in_namespace() {
+        assert!(PlayerEvents::is_in_namespace("sync", "sync"));
+        assert!(PlayerEvents::is_in_namespace("sync.drift", "sync"));
+        assert!(PlayerEvents::is_in_namespace(
+            "sync.drift.fast",
+            "sync.drift"
+        ));
+
+        assert!(!PlayerEvents::is_in_namespace("sync", "sync.drift"));
+        assert!(!PlayerEvents::is_in_namespace("synchronize", "sync"));
+        assert!(!PlayerEvents::is_in_namespace("ui.sync", "sync"));
+    }
+
     #[test]
     fn get_namespaced_event_empty() {
         let event_name: &str = "";
@@ -176,6 +415,38 @@ mod tests {
         assert_eq!(true, namespaced_event.is_err());
     }
 
+    #[test]
+    fn player_event_name_typings() {
+        let mut declared: Vec<&str> = PlayerEventName::ALL
+            .iter()
+            .map(|event_name| event_name.as_str())
+            .collect();
+        let mut events: Vec<&str> = PlayerEvents::API_EVENTS
+            .iter()
+            .chain(PlayerEvents::SYNTHETIC_EVENTS.iter())
+            .chain([PlayerEvents::WILDCARD].iter())
+            .copied()
+            .collect();
+
+        declared.sort_unstable();
+        events.sort_unstable();
+
+        assert_eq!(events, declared);
+
+        // declared values are the serialized names, which have to match the event names
+        for event_name in PlayerEventName::ALL {
+            assert_eq!(
+                serde_json::json!(event_name.as_str()),
+                serde_json::to_value(event_name).unwrap()
+            );
+        }
+
+        assert_eq!(
+            "export const enum PlayerEventName {\n  READY = 'ready',\n  ERROR = 'error',\n  STATE_CHANGE = 'stateChange',\n  PLAYBACK_QUALITY_CHANGE = 'playbackQualityChange',\n  PLAYBACK_RATE_CHANGE = 'playbackRateChange',\n  API_CHANGE = 'apiChange',\n  VOLUME_CHANGE = 'volumeChange',\n  TIME_UPDATE = 'timeUpdate',\n  PROGRESS = 'progress',\n  SEEKED = 'seeked',\n  STALLED = 'stalled',\n  WILDCARD = '*',\n}",
+            PlayerEventName::DECLARATION
+        );
+    }
+
     // #[bench]
     // fn bench_with_capacity(b: &mut Bencher) {
     //     let event_name: &str = PlayerEvents::STATE_CHANGE;
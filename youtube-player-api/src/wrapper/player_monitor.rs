@@ -0,0 +1,305 @@
+use alloc::{vec, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+use super::PlayerState;
+use crate::TypeScript;
+
+/// Settings of the playback monitor, which emits `seeked` and `stalled` events.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TypeScript)]
+#[serde(default)]
+pub struct MonitorOptions {
+    /// Milliseconds between two checks of the playback position.
+    pub interval: u32,
+    /// Seconds the position may differ from the expected position before it counts as seek.
+    #[serde(rename = "seekThreshold")]
+    pub seek_threshold: f64,
+    /// Seconds of buffering before it counts as stall.
+    #[serde(rename = "stallThreshold")]
+    pub stall_threshold: f64,
+}
+
+impl MonitorOptions {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn interval(mut self, interval: u32) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn seek_threshold(mut self, seek_threshold: f64) -> Self {
+        self.seek_threshold = seek_threshold;
+        self
+    }
+
+    pub fn stall_threshold(mut self, stall_threshold: f64) -> Self {
+        self.stall_threshold = stall_threshold;
+        self
+    }
+}
+
+impl Default for MonitorOptions {
+    fn default() -> Self {
+        Self {
+            interval: 250,
+            seek_threshold: 1.0,
+            stall_threshold: 3.0,
+        }
+    }
+}
+
+/// Data of event `seeked`, positions in seconds.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TypeScript)]
+pub struct Seek {
+    pub from: f64,
+    pub to: f64,
+}
+
+/// Data of event `stalled`, seconds the player has been buffering so far.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TypeScript)]
+pub struct Stall {
+    pub duration: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum MonitorEvent {
+    Seeked(Seek),
+    Stalled(Stall),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Sample {
+    wall_time: f64,
+    state: PlayerState,
+    current_time: f64,
+    playback_rate: f64,
+}
+
+/// Detects seeks and stalls by comparing elapsed wall time with the playback position.
+#[derive(Clone, Debug)]
+pub(crate) struct PlaybackMonitor {
+    options: MonitorOptions,
+    previous: Option<Sample>,
+    buffering_since: Option<f64>,
+    stall_reported: bool,
+}
+
+impl PlaybackMonitor {
+    pub(crate) fn new(options: MonitorOptions) -> Self {
+        Self {
+            options,
+            previous: None,
+            buffering_since: None,
+            stall_reported: false,
+        }
+    }
+
+    /// Add a sample of the player, `wall_time` in milliseconds and the others as reported by the player.
+    pub(crate) fn sample(
+        &mut self,
+        wall_time: f64,
+        state: PlayerState,
+        current_time: f64,
+        playback_rate: f64,
+    ) -> Vec<MonitorEvent> {
+        let mut events = vec![];
+
+        // player doesn't provide a position yet
+        if !current_time.is_finite() {
+            return events;
+        }
+
+        let sample = Sample {
+            wall_time,
+            state,
+            current_time,
+            playback_rate: if playback_rate.is_finite() {
+                playback_rate
+            } else {
+                1.0
+            },
+        };
+
+        // a new video starts at its own position, which isn't a seek
+        if matches!(state, PlayerState::Unstarted | PlayerState::Cued) {
+            self.previous = None;
+            self.buffering_since = None;
+            self.stall_reported = false;
+
+            return events;
+        }
+
+        if let Some(seek) = self
+            .previous
+            .and_then(|previous| self.detect_seek(previous, sample))
+        {
+            events.push(MonitorEvent::Seeked(seek));
+        }
+
+        if let Some(stall) = self.detect_stall(sample) {
+            events.push(MonitorEvent::Stalled(stall));
+        }
+
+        self.previous = Some(sample);
+
+        events
+    }
+
+    fn detect_seek(&self, previous: Sample, sample: Sample) -> Option<Seek> {
+        // position only advances while playing
+        let expected_time = if previous.state == PlayerState::Playing {
+            let elapsed = (sample.wall_time - previous.wall_time) / 1000.0;
+            previous.current_time + elapsed * previous.playback_rate
+        } else {
+            previous.current_time
+        };
+
+        if (sample.current_time - expected_time).abs() <= self.options.seek_threshold {
+            return None;
+        }
+
+        Some(Seek {
+            from: previous.current_time,
+            to: sample.current_time,
+        })
+    }
+
+    fn detect_stall(&mut self, sample: Sample) -> Option<Stall> {
+        if sample.state != PlayerState::Buffering {
+            self.buffering_since = None;
+            self.stall_reported = false;
+
+            return None;
+        }
+
+        let buffering_since = *self.buffering_since.get_or_insert(sample.wall_time);
+        let duration = (sample.wall_time - buffering_since) / 1000.0;
+
+        // report every buffering period only once
+        if self.stall_reported || duration < self.options.stall_threshold {
+            return None;
+        }
+
+        self.stall_reported = true;
+
+        Some(Stall { duration })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monitor_options_default() {
+        let options = MonitorOptions::new();
+
+        assert_eq!(250, options.interval);
+        assert_eq!(1.0, options.seek_threshold);
+        assert_eq!(3.0, options.stall_threshold);
+    }
+
+    #[test]
+    fn monitor_options_set() {
+        let options = MonitorOptions::new()
+            .interval(100)
+            .seek_threshold(2.0)
+            .stall_threshold(5.0);
+
+        assert_eq!(100, options.interval);
+        assert_eq!(2.0, options.seek_threshold);
+        assert_eq!(5.0, options.stall_threshold);
+    }
+
+    #[test]
+    fn monitor_regular_playback() {
+        let mut monitor = PlaybackMonitor::new(MonitorOptions::new());
+
+        assert!(monitor
+            .sample(0.0, PlayerState::Playing, 10.0, 1.0)
+            .is_empty());
+        assert!(monitor
+            .sample(250.0, PlayerState::Playing, 10.25, 1.0)
+            .is_empty());
+        assert!(monitor
+            .sample(500.0, PlayerState::Playing, 10.75, 2.0)
+            .is_empty());
+        assert!(monitor
+            .sample(750.0, PlayerState::Paused, 11.25, 2.0)
+            .is_empty());
+        assert!(monitor
+            .sample(5000.0, PlayerState::Paused, 11.25, 2.0)
+            .is_empty());
+    }
+
+    #[test]
+    fn monitor_seeked() {
+        let mut monitor = PlaybackMonitor::new(MonitorOptions::new());
+
+        monitor.sample(0.0, PlayerState::Playing, 10.0, 1.0);
+
+        assert_eq!(
+            vec![MonitorEvent::Seeked(Seek {
+                from: 10.0,
+                to: 60.0
+            })],
+            monitor.sample(250.0, PlayerState::Playing, 60.0, 1.0)
+        );
+
+        // seek backwards while paused
+        monitor.sample(500.0, PlayerState::Paused, 60.25, 1.0);
+
+        assert_eq!(
+            vec![MonitorEvent::Seeked(Seek {
+                from: 60.25,
+                to: 5.0
+            })],
+            monitor.sample(750.0, PlayerState::Paused, 5.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn monitor_new_video_isnt_seek() {
+        let mut monitor = PlaybackMonitor::new(MonitorOptions::new());
+
+        monitor.sample(0.0, PlayerState::Playing, 120.0, 1.0);
+        monitor.sample(250.0, PlayerState::Unstarted, 0.0, 1.0);
+
+        assert!(monitor
+            .sample(500.0, PlayerState::Playing, 0.1, 1.0)
+            .is_empty());
+    }
+
+    #[test]
+    fn monitor_stalled_once() {
+        let mut monitor = PlaybackMonitor::new(MonitorOptions::new().stall_threshold(1.0));
+
+        assert!(monitor
+            .sample(0.0, PlayerState::Buffering, 10.0, 1.0)
+            .is_empty());
+        assert!(monitor
+            .sample(500.0, PlayerState::Buffering, 10.0, 1.0)
+            .is_empty());
+        assert_eq!(
+            vec![MonitorEvent::Stalled(Stall { duration: 1.0 })],
+            monitor.sample(1000.0, PlayerState::Buffering, 10.0, 1.0)
+        );
+        assert!(monitor
+            .sample(1500.0, PlayerState::Buffering, 10.0, 1.0)
+            .is_empty());
+
+        // next buffering period
+        monitor.sample(2000.0, PlayerState::Playing, 10.0, 1.0);
+
+        assert!(monitor
+            .sample(2250.0, PlayerState::Buffering, 10.25, 1.0)
+            .is_empty());
+        assert_eq!(
+            vec![MonitorEvent::Stalled(Stall { duration: 1.5 })],
+            monitor.sample(3750.0, PlayerState::Buffering, 10.25, 1.0)
+        );
+    }
+}
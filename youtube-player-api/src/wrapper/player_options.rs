@@ -1,13 +1,112 @@
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use js_sys::Object;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::{JsCast, JsValue};
 
-// #[wasm_bindgen(typescript_type = "PlayerVars")]
-#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+use super::{MonitorOptions, PendingCommandPolicy};
+use crate::TypeScript;
+
+/// Annotation policy of player parameter `iv_load_policy`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, TypeScript)]
+#[serde(try_from = "u8", into = "u8")]
+#[ts(const_enum)]
+pub enum IvLoadPolicy {
+    #[default]
+    Show = 1,
+    Hide = 3,
+}
+
+impl TryFrom<u8> for IvLoadPolicy {
+    type Error = &'static str;
+
+    fn try_from(policy: u8) -> Result<Self, Self::Error> {
+        match policy {
+            1 => Ok(Self::Show),
+            3 => Ok(Self::Hide),
+            _ => Err("Annotation policy must be 1 (show) or 3 (hide)!"),
+        }
+    }
+}
+
+impl From<IvLoadPolicy> for u8 {
+    fn from(policy: IvLoadPolicy) -> Self {
+        policy as u8
+    }
+}
+
+/// Player parameters, see <https://developers.google.com/youtube/player_parameters#Parameters>.
+///
+/// Parameters only accepting `0` or `1` are represented as booleans.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, TypeScript)]
+#[serde(default)]
 pub struct PlayerVars {
-    pub autoplay: Option<i32>,
-    pub controls: Option<i32>,
+    #[serde(with = "numeric_bool", skip_serializing_if = "Option::is_none")]
+    #[ts(type = "0 | 1")]
+    pub autoplay: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cc_lang_pref: Option<String>,
+    #[serde(with = "numeric_bool", skip_serializing_if = "Option::is_none")]
+    #[ts(type = "0 | 1")]
+    pub cc_load_policy: Option<bool>,
+    #[serde(with = "numeric_bool", skip_serializing_if = "Option::is_none")]
+    #[ts(type = "0 | 1")]
+    pub controls: Option<bool>,
+    #[serde(
+        rename = "disablekb",
+        with = "numeric_bool",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[ts(type = "0 | 1")]
+    pub disable_kb: Option<bool>,
+    #[serde(
+        rename = "enablejsapi",
+        with = "numeric_bool",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[ts(type = "0 | 1")]
+    pub enable_js_api: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<u32>,
+    #[serde(
+        rename = "fs",
+        with = "numeric_bool",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[ts(type = "0 | 1")]
+    pub fullscreen: Option<bool>,
+    #[serde(rename = "hl", skip_serializing_if = "Option::is_none")]
+    pub interface_language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iv_load_policy: Option<IvLoadPolicy>,
+    #[serde(
+        rename = "loop",
+        with = "numeric_bool",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[ts(type = "0 | 1")]
+    pub loop_playback: Option<bool>,
+    #[serde(with = "numeric_bool", skip_serializing_if = "Option::is_none")]
+    #[ts(type = "0 | 1")]
+    pub mute: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+    #[serde(with = "comma_separated", skip_serializing_if = "Option::is_none")]
+    #[ts(type = "string")]
+    pub playlist: Option<Vec<String>>,
+    #[serde(
+        rename = "playsinline",
+        with = "numeric_bool",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[ts(type = "0 | 1")]
+    pub plays_inline: Option<bool>,
+    #[serde(with = "numeric_bool", skip_serializing_if = "Option::is_none")]
+    #[ts(type = "0 | 1")]
+    pub rel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub widget_referrer: Option<String>,
 }
 
 impl PlayerVars {
@@ -17,26 +116,128 @@ impl PlayerVars {
         }
     }
 
-    pub fn autoplay(mut self, autoplay: i32) -> Self {
+    pub fn autoplay(mut self, autoplay: bool) -> Self {
         self.autoplay = Some(autoplay);
         self
     }
 
-    pub fn controls(mut self, controls: i32) -> Self {
+    /// Preferred caption language as ISO 639-1 two-letter language code.
+    pub fn cc_lang_pref(mut self, cc_lang_pref: String) -> Self {
+        self.cc_lang_pref = Some(cc_lang_pref);
+        self
+    }
+
+    pub fn cc_load_policy(mut self, cc_load_policy: bool) -> Self {
+        self.cc_load_policy = Some(cc_load_policy);
+        self
+    }
+
+    pub fn controls(mut self, controls: bool) -> Self {
         self.controls = Some(controls);
         self
     }
+
+    pub fn disable_kb(mut self, disable_kb: bool) -> Self {
+        self.disable_kb = Some(disable_kb);
+        self
+    }
+
+    pub fn enable_js_api(mut self, enable_js_api: bool) -> Self {
+        self.enable_js_api = Some(enable_js_api);
+        self
+    }
+
+    /// Stop playback after given seconds, measured from the start of the video.
+    pub fn end(mut self, end: u32) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    pub fn fullscreen(mut self, fullscreen: bool) -> Self {
+        self.fullscreen = Some(fullscreen);
+        self
+    }
+
+    /// Player interface language as ISO 639-1 two-letter language code or fully specified locale.
+    pub fn interface_language(mut self, interface_language: String) -> Self {
+        self.interface_language = Some(interface_language);
+        self
+    }
+
+    pub fn iv_load_policy(mut self, iv_load_policy: IvLoadPolicy) -> Self {
+        self.iv_load_policy = Some(iv_load_policy);
+        self
+    }
+
+    /// Single videos additionally need parameter `playlist` set to their own video ID.
+    pub fn loop_playback(mut self, loop_playback: bool) -> Self {
+        self.loop_playback = Some(loop_playback);
+        self
+    }
+
+    pub fn mute(mut self, mute: bool) -> Self {
+        self.mute = Some(mute);
+        self
+    }
+
+    /// Should be set to the domain of the page if `enable_js_api` is used.
+    pub fn origin(mut self, origin: String) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Video IDs played after the video set by `PlayerOptions::video_id`.
+    pub fn playlist(mut self, playlist: Vec<String>) -> Self {
+        self.playlist = Some(playlist);
+        self
+    }
+
+    pub fn plays_inline(mut self, plays_inline: bool) -> Self {
+        self.plays_inline = Some(plays_inline);
+        self
+    }
+
+    /// Disabled only restricts related videos to the channel of the played video.
+    pub fn rel(mut self, rel: bool) -> Self {
+        self.rel = Some(rel);
+        self
+    }
+
+    /// Start playback at given seconds, measured from the start of the video.
+    pub fn start(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn widget_referrer(mut self, widget_referrer: String) -> Self {
+        self.widget_referrer = Some(widget_referrer);
+        self
+    }
 }
 
-// #[wasm_bindgen(typescript_type = "PlayerOptions")]
-#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+/// Event handlers (option `events`) can only be set from JS, see `YtPlayer::on` instead.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, TypeScript)]
 pub struct PlayerOptions {
     #[serde(rename = "videoId")]
     pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
     pub width: Option<i32>,
     pub height: Option<i32>,
     #[serde(rename = "playerVars")]
     pub player_vars: Option<PlayerVars>,
+    /// Wrapper option, isn't forwarded to the Youtube API.
+    #[serde(rename = "pendingCommands", skip_serializing_if = "Option::is_none")]
+    pub pending_commands: Option<PendingCommandPolicy>,
+    /// Wrapper option, isn't forwarded to the Youtube API.
+    #[serde(rename = "timeUpdateInterval", skip_serializing_if = "Option::is_none")]
+    pub time_update_interval: Option<u32>,
+    /// Wrapper option, isn't forwarded to the Youtube API.
+    #[serde(rename = "playbackMonitor", skip_serializing_if = "Option::is_none")]
+    pub playback_monitor: Option<MonitorOptions>,
+    /// Wrapper option, isn't forwarded to the Youtube API.
+    #[serde(rename = "aspectRatio", skip_serializing_if = "Option::is_none")]
+    pub aspect_ratio: Option<f64>,
 }
 
 impl PlayerOptions {
@@ -51,6 +252,12 @@ impl PlayerOptions {
         self
     }
 
+    /// Embed host, e.g. `https://www.youtube-nocookie.com`, defaults to `LoaderOptions::host`.
+    pub fn host(mut self, host: String) -> Self {
+        self.host = Some(host);
+        self
+    }
+
     pub fn width(mut self, width: i32) -> Self {
         self.width = Some(width);
         self
@@ -65,6 +272,30 @@ impl PlayerOptions {
         self.player_vars = Some(player_vars);
         self
     }
+
+    /// Handling of commands issued before the player is ready, queued by default.
+    pub fn pending_commands(mut self, pending_commands: PendingCommandPolicy) -> Self {
+        self.pending_commands = Some(pending_commands);
+        self
+    }
+
+    /// Milliseconds between `timeUpdate` and `progress` events, disabled by default.
+    pub fn time_update_interval(mut self, time_update_interval: u32) -> Self {
+        self.time_update_interval = Some(time_update_interval);
+        self
+    }
+
+    /// Emit `seeked` and `stalled` events, disabled by default.
+    pub fn playback_monitor(mut self, playback_monitor: MonitorOptions) -> Self {
+        self.playback_monitor = Some(playback_monitor);
+        self
+    }
+
+    /// Resize the player to fill its parent width with the given ratio (width / height), e.g. `16.0 / 9.0`.
+    pub fn aspect_ratio(mut self, aspect_ratio: f64) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
 }
 
 impl From<Object> for PlayerOptions {
@@ -92,28 +323,152 @@ impl From<PlayerOptions> for JsValue {
     }
 }
 
+// Youtube API expects `0` or `1` for boolean parameters, also accept booleans from JS.
+mod numeric_bool {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumericBool {
+        Bool(bool),
+        Number(u8),
+    }
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<bool>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(u8::from).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<bool>, D::Error> {
+        let value = Option::<NumericBool>::deserialize(deserializer)?;
+
+        Ok(value.map(|value| match value {
+            NumericBool::Bool(value) => value,
+            NumericBool::Number(value) => value != 0,
+        }))
+    }
+}
+
+// Youtube API expects a comma-separated list of video IDs for parameter `playlist`.
+mod comma_separated {
+    use alloc::{borrow::ToOwned, string::String, vec::Vec};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Vec<String>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value
+            .as_ref()
+            .map(|list| list.join(","))
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<String>>, D::Error> {
+        let value = Option::<String>::deserialize(deserializer)?;
+
+        Ok(value.map(|list| {
+            list.split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .map(|entry| entry.to_owned())
+                .collect()
+        }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use alloc::borrow::ToOwned;
+    use alloc::{borrow::ToOwned, vec};
 
     #[test]
     fn player_vars_default() {
-        // FIXME add all available fields from official youtube api
         let player_vars = PlayerVars::new();
 
         assert_eq!(None, player_vars.autoplay);
+        assert_eq!(None, player_vars.cc_lang_pref);
+        assert_eq!(None, player_vars.cc_load_policy);
         assert_eq!(None, player_vars.controls);
+        assert_eq!(None, player_vars.disable_kb);
+        assert_eq!(None, player_vars.enable_js_api);
+        assert_eq!(None, player_vars.end);
+        assert_eq!(None, player_vars.fullscreen);
+        assert_eq!(None, player_vars.interface_language);
+        assert_eq!(None, player_vars.iv_load_policy);
+        assert_eq!(None, player_vars.loop_playback);
+        assert_eq!(None, player_vars.mute);
+        assert_eq!(None, player_vars.origin);
+        assert_eq!(None, player_vars.playlist);
+        assert_eq!(None, player_vars.plays_inline);
+        assert_eq!(None, player_vars.rel);
+        assert_eq!(None, player_vars.start);
+        assert_eq!(None, player_vars.widget_referrer);
     }
 
     #[test]
     fn player_vars_set() {
-        // FIXME add all available fields from official youtube api
-        let player_vars = PlayerVars::new().autoplay(0).controls(1);
+        let player_vars = PlayerVars::new()
+            .autoplay(false)
+            .cc_lang_pref("de".to_owned())
+            .cc_load_policy(true)
+            .controls(true)
+            .disable_kb(true)
+            .enable_js_api(true)
+            .end(120)
+            .fullscreen(false)
+            .interface_language("en-US".to_owned())
+            .iv_load_policy(IvLoadPolicy::Hide)
+            .loop_playback(true)
+            .mute(true)
+            .origin("https://example.com".to_owned())
+            .playlist(vec!["cE0wfjsybIQ".to_owned(), "bS4Q-WWyl3Q".to_owned()])
+            .plays_inline(true)
+            .rel(false)
+            .start(30)
+            .widget_referrer("https://example.com/room".to_owned());
+
+        assert_eq!(Some(false), player_vars.autoplay);
+        assert_eq!(Some("de".to_owned()), player_vars.cc_lang_pref);
+        assert_eq!(Some(true), player_vars.cc_load_policy);
+        assert_eq!(Some(true), player_vars.controls);
+        assert_eq!(Some(true), player_vars.disable_kb);
+        assert_eq!(Some(true), player_vars.enable_js_api);
+        assert_eq!(Some(120), player_vars.end);
+        assert_eq!(Some(false), player_vars.fullscreen);
+        assert_eq!(Some("en-US".to_owned()), player_vars.interface_language);
+        assert_eq!(Some(IvLoadPolicy::Hide), player_vars.iv_load_policy);
+        assert_eq!(Some(true), player_vars.loop_playback);
+        assert_eq!(Some(true), player_vars.mute);
+        assert_eq!(Some("https://example.com".to_owned()), player_vars.origin);
+        assert_eq!(
+            Some(vec!["cE0wfjsybIQ".to_owned(), "bS4Q-WWyl3Q".to_owned()]),
+            player_vars.playlist
+        );
+        assert_eq!(Some(true), player_vars.plays_inline);
+        assert_eq!(Some(false), player_vars.rel);
+        assert_eq!(Some(30), player_vars.start);
+        assert_eq!(
+            Some("https://example.com/room".to_owned()),
+            player_vars.widget_referrer
+        );
+    }
+
+    #[test]
+    fn iv_load_policy_convert() {
+        assert_eq!(Ok(IvLoadPolicy::Show), IvLoadPolicy::try_from(1));
+        assert_eq!(Ok(IvLoadPolicy::Hide), IvLoadPolicy::try_from(3));
+        assert!(IvLoadPolicy::try_from(2).is_err());
 
-        assert_eq!(Some(0), player_vars.autoplay);
-        assert_eq!(Some(1), player_vars.controls);
+        assert_eq!(1, u8::from(IvLoadPolicy::Show));
+        assert_eq!(3, u8::from(IvLoadPolicy::Hide));
     }
 
     #[test]
@@ -121,9 +476,14 @@ mod tests {
         let player_options = PlayerOptions::new();
 
         assert_eq!(None, player_options.video_id);
+        assert_eq!(None, player_options.host);
         assert_eq!(None, player_options.width);
         assert_eq!(None, player_options.height);
         assert_eq!(None, player_options.player_vars);
+        assert_eq!(None, player_options.pending_commands);
+        assert_eq!(None, player_options.time_update_interval);
+        assert_eq!(None, player_options.playback_monitor);
+        assert_eq!(None, player_options.aspect_ratio);
     }
 
     #[test]
@@ -131,13 +491,29 @@ mod tests {
         let player_vars = PlayerVars::new();
         let player_options = PlayerOptions::new()
             .video_id("abcdefghij".to_owned())
+            .host("https://www.youtube-nocookie.com".to_owned())
             .width(640)
             .height(360)
-            .player_vars(player_vars.clone());
+            .player_vars(player_vars.clone())
+            .pending_commands(PendingCommandPolicy::Drop)
+            .time_update_interval(250)
+            .playback_monitor(MonitorOptions::new())
+            .aspect_ratio(16.0 / 9.0);
 
         assert_eq!(Some("abcdefghij".to_owned()), player_options.video_id);
+        assert_eq!(
+            Some("https://www.youtube-nocookie.com".to_owned()),
+            player_options.host
+        );
         assert_eq!(Some(640), player_options.width);
         assert_eq!(Some(360), player_options.height);
         assert_eq!(Some(player_vars), player_options.player_vars);
+        assert_eq!(
+            Some(PendingCommandPolicy::Drop),
+            player_options.pending_commands
+        );
+        assert_eq!(Some(250), player_options.time_update_interval);
+        assert_eq!(Some(MonitorOptions::new()), player_options.playback_monitor);
+        assert_eq!(Some(16.0 / 9.0), player_options.aspect_ratio);
     }
 }
@@ -0,0 +1,139 @@
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::TypeScript;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TypeScript)]
+#[serde(rename_all = "snake_case")]
+pub enum ListType {
+    Playlist,
+    UserUploads,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TypeScript)]
+#[serde(untagged)]
+pub enum PlaylistSource {
+    /// Plain list of video IDs, uses argument syntax of `cuePlaylist`/`loadPlaylist`.
+    Videos { playlist: Vec<String> },
+    /// Playlist ID or user ID, uses object syntax of `cuePlaylist`/`loadPlaylist`.
+    List {
+        #[serde(rename = "listType")]
+        list_type: ListType,
+        list: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TypeScript)]
+pub struct PlaylistRequest {
+    #[serde(flatten)]
+    pub source: PlaylistSource,
+    pub index: Option<u32>,
+    #[serde(rename = "startSeconds")]
+    pub start_seconds: Option<f64>,
+}
+
+impl PlaylistRequest {
+    pub fn new(source: PlaylistSource) -> Self {
+        Self {
+            source,
+            index: None,
+            start_seconds: None,
+        }
+    }
+
+    pub fn videos(video_ids: Vec<String>) -> Self {
+        Self::new(PlaylistSource::Videos {
+            playlist: video_ids,
+        })
+    }
+
+    pub fn playlist(playlist_id: String) -> Self {
+        Self::new(PlaylistSource::List {
+            list_type: ListType::Playlist,
+            list: playlist_id,
+        })
+    }
+
+    pub fn user_uploads(user_id: String) -> Self {
+        Self::new(PlaylistSource::List {
+            list_type: ListType::UserUploads,
+            list: user_id,
+        })
+    }
+
+    pub fn index(mut self, index: u32) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    pub fn start_seconds(mut self, start_seconds: f64) -> Self {
+        self.start_seconds = Some(start_seconds);
+        self
+    }
+}
+
+impl TryFrom<JsValue> for PlaylistRequest {
+    type Error = serde_wasm_bindgen::Error;
+
+    fn try_from(request: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(request)
+    }
+}
+
+impl TryFrom<&PlaylistRequest> for JsValue {
+    type Error = serde_wasm_bindgen::Error;
+
+    fn try_from(request: &PlaylistRequest) -> Result<Self, Self::Error> {
+        super::to_js_object(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{borrow::ToOwned, vec};
+
+    #[test]
+    fn playlist_request_videos() {
+        let video_ids = vec!["cE0wfjsybIQ".to_owned(), "bS4Q-WWyl3Q".to_owned()];
+        let request = PlaylistRequest::videos(video_ids.clone());
+
+        assert_eq!(
+            PlaylistSource::Videos {
+                playlist: video_ids
+            },
+            request.source
+        );
+        assert_eq!(None, request.index);
+        assert_eq!(None, request.start_seconds);
+    }
+
+    #[test]
+    fn playlist_request_list() {
+        let request = PlaylistRequest::playlist("PLabcdefghij".to_owned())
+            .index(3)
+            .start_seconds(42.5);
+
+        assert_eq!(
+            PlaylistSource::List {
+                list_type: ListType::Playlist,
+                list: "PLabcdefghij".to_owned()
+            },
+            request.source
+        );
+        assert_eq!(Some(3), request.index);
+        assert_eq!(Some(42.5), request.start_seconds);
+
+        let request = PlaylistRequest::user_uploads("someuser".to_owned());
+
+        assert_eq!(
+            PlaylistSource::List {
+                list_type: ListType::UserUploads,
+                list: "someuser".to_owned()
+            },
+            request.source
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use alloc::boxed::Box;
+use core::fmt;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{console, window};
+
+/// Runs a function repeatedly with `setInterval`, the interval is cleared on `stop` or drop.
+pub(crate) struct Poller {
+    running: Option<(i32, Closure<dyn FnMut()>)>,
+}
+
+impl Poller {
+    pub(crate) fn new() -> Self {
+        Self { running: None }
+    }
+
+    /// Start polling, replaces the previously running function.
+    pub(crate) fn start(&mut self, interval: u32, tick: Box<dyn FnMut()>) {
+        self.stop();
+
+        let window = match window() {
+            Some(window) => window,
+            None => return,
+        };
+
+        let tick = Closure::wrap(tick);
+
+        let interval_id = window.set_interval_with_callback_and_timeout_and_arguments_0(
+            tick.as_ref().unchecked_ref(),
+            interval.try_into().unwrap_or(i32::MAX),
+        );
+
+        match interval_id {
+            Ok(interval_id) => self.running = Some((interval_id, tick)),
+            Err(error) => console::error_1(&error),
+        }
+    }
+
+    pub(crate) fn stop(&mut self) {
+        if let Some((interval_id, _tick)) = self.running.take() {
+            if let Some(window) = window() {
+                window.clear_interval_with_handle(interval_id);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Poller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Poller")
+            .field("running", &self.running.is_some())
+            .finish()
+    }
+}
+
+impl Drop for Poller {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
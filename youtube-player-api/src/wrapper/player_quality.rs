@@ -0,0 +1,127 @@
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use crate::TypeScript;
+
+/// Quality levels as reported by `getAvailableQualityLevels` and the `playbackQualityChange` event.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, TypeScript)]
+#[serde(from = "String", into = "String")]
+#[ts(rename_all = "lowercase")]
+pub enum PlaybackQuality {
+    Tiny,
+    Small,
+    Medium,
+    Large,
+    Hd720,
+    Hd1080,
+    Hd1440,
+    Hd2160,
+    HighRes,
+    #[default]
+    Auto,
+    /// Value is `"default"`, the player chooses the quality on its own.
+    Default,
+    /// Quality level not (yet) known by this library.
+    #[ts(skip)]
+    Unknown(String),
+}
+
+impl PlaybackQuality {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Tiny => "tiny",
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+            Self::Hd720 => "hd720",
+            Self::Hd1080 => "hd1080",
+            Self::Hd1440 => "hd1440",
+            Self::Hd2160 => "hd2160",
+            Self::HighRes => "highres",
+            Self::Auto => "auto",
+            Self::Default => "default",
+            Self::Unknown(quality) => quality,
+        }
+    }
+}
+
+impl From<&str> for PlaybackQuality {
+    fn from(quality: &str) -> Self {
+        match quality {
+            "tiny" => Self::Tiny,
+            "small" => Self::Small,
+            "medium" => Self::Medium,
+            "large" => Self::Large,
+            "hd720" => Self::Hd720,
+            "hd1080" => Self::Hd1080,
+            "hd1440" => Self::Hd1440,
+            "hd2160" => Self::Hd2160,
+            "highres" => Self::HighRes,
+            "auto" => Self::Auto,
+            "default" => Self::Default,
+            quality => Self::Unknown(quality.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlaybackQuality {
+    fn from(quality: String) -> Self {
+        Self::from(quality.as_str())
+    }
+}
+
+impl From<PlaybackQuality> for String {
+    fn from(quality: PlaybackQuality) -> Self {
+        match quality {
+            PlaybackQuality::Unknown(quality) => quality,
+            quality => quality.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PlaybackQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString;
+
+    #[test]
+    fn playback_quality_convert() {
+        for quality in [
+            PlaybackQuality::Tiny,
+            PlaybackQuality::Small,
+            PlaybackQuality::Medium,
+            PlaybackQuality::Large,
+            PlaybackQuality::Hd720,
+            PlaybackQuality::Hd1080,
+            PlaybackQuality::Hd1440,
+            PlaybackQuality::Hd2160,
+            PlaybackQuality::HighRes,
+            PlaybackQuality::Auto,
+            PlaybackQuality::Default,
+        ] {
+            assert_eq!(quality, PlaybackQuality::from(quality.to_string()));
+            assert_eq!(quality.as_str(), String::from(quality.clone()));
+        }
+
+        assert_eq!("hd720", PlaybackQuality::Hd720.to_string());
+        assert_eq!("highres", PlaybackQuality::HighRes.as_str());
+    }
+
+    #[test]
+    fn playback_quality_unknown() {
+        let quality = PlaybackQuality::from("hd4320");
+
+        assert_eq!(PlaybackQuality::Unknown("hd4320".to_owned()), quality);
+        assert_eq!("hd4320", quality.as_str());
+        assert_eq!("hd4320", String::from(quality));
+    }
+}
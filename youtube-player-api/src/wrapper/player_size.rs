@@ -0,0 +1,72 @@
+use alloc::boxed::Box;
+use core::fmt;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::HtmlIFrameElement;
+
+use super::api::{PlayerInstance, ResizeObserver};
+
+/// Size in pixels of a player filling the given width with a fixed aspect ratio (width / height).
+pub(crate) fn fit_aspect_ratio(width: f64, aspect_ratio: f64) -> (f64, f64) {
+    let width = width.max(0.0).floor();
+
+    (width, (width / aspect_ratio).round())
+}
+
+/// Keeps the player at a fixed aspect ratio inside its parent element, stops observing on drop.
+pub(crate) struct ResizeBinding {
+    observer: ResizeObserver,
+    _callback: Closure<dyn FnMut(JsValue)>,
+}
+
+impl ResizeBinding {
+    pub(crate) fn new(instance: &PlayerInstance, aspect_ratio: f64) -> Result<Self, JsValue> {
+        let iframe = instance.get_iframe().dyn_into::<HtmlIFrameElement>()?;
+        let parent = iframe.parent_element().ok_or(JsValue::undefined())?;
+
+        let observed_parent = parent.clone();
+        let instance = instance.clone();
+
+        // observers also run once for the initial size
+        let callback = Closure::wrap(Box::new(move |_entries: JsValue| {
+            let (width, height) =
+                fit_aspect_ratio(observed_parent.client_width().into(), aspect_ratio);
+
+            instance.set_size(width, height);
+        }) as Box<dyn FnMut(JsValue)>);
+
+        let observer = ResizeObserver::new(callback.as_ref().unchecked_ref())?;
+        observer.observe(&parent);
+
+        Ok(Self {
+            observer,
+            _callback: callback,
+        })
+    }
+}
+
+impl fmt::Debug for ResizeBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResizeBinding")
+            .field("observer", &self.observer)
+            .finish()
+    }
+}
+
+impl Drop for ResizeBinding {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_aspect_ratio_size() {
+        assert_eq!((640.0, 360.0), fit_aspect_ratio(640.0, 16.0 / 9.0));
+        assert_eq!((801.0, 451.0), fit_aspect_ratio(801.7, 16.0 / 9.0));
+        assert_eq!((400.0, 300.0), fit_aspect_ratio(400.0, 4.0 / 3.0));
+        assert_eq!((0.0, 0.0), fit_aspect_ratio(-10.0, 16.0 / 9.0));
+    }
+}
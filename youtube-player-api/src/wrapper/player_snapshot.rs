@@ -0,0 +1,165 @@
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::{PlaybackQuality, PlayerState, PlaylistRequest, VideoRequest, Volume};
+use crate::TypeScript;
+
+/// Playback status captured by `YtPlayer::snapshot`, e.g. to continue after a page reload.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TypeScript)]
+pub struct PlayerSnapshot {
+    /// Missing if no video was loaded.
+    #[serde(rename = "videoId")]
+    pub video_id: Option<String>,
+    /// Seconds since the start of the video.
+    #[serde(rename = "currentTime")]
+    pub current_time: f64,
+    pub state: PlayerState,
+    #[serde(rename = "playbackRate")]
+    pub playback_rate: f64,
+    pub volume: Volume,
+    pub muted: bool,
+    #[serde(rename = "playbackQuality")]
+    pub playback_quality: PlaybackQuality,
+    /// Video IDs of the loaded playlist, empty without playlist.
+    #[serde(default)]
+    pub playlist: Vec<String>,
+    #[serde(rename = "playlistIndex")]
+    pub playlist_index: Option<u32>,
+}
+
+impl PlayerSnapshot {
+    /// Whether the video was playing, restoring loads it instead of cueing it.
+    pub fn is_playing(&self) -> bool {
+        matches!(self.state, PlayerState::Playing | PlayerState::Buffering)
+    }
+
+    /// Request of the video at the captured position, `None` without video ID.
+    pub fn video_request(&self) -> Option<VideoRequest> {
+        let video_id = self.video_id.clone()?;
+
+        Some(VideoRequest::by_id(video_id).start_seconds(self.current_time))
+    }
+
+    /// Request of the playlist at the captured video and position, `None` without playlist.
+    pub fn playlist_request(&self) -> Option<PlaylistRequest> {
+        if self.playlist.is_empty() {
+            return None;
+        }
+
+        let mut request =
+            PlaylistRequest::videos(self.playlist.clone()).start_seconds(self.current_time);
+        request.index = self.playlist_index;
+
+        Some(request)
+    }
+}
+
+// values of a player which isn't ready
+impl Default for PlayerSnapshot {
+    fn default() -> Self {
+        Self {
+            video_id: None,
+            current_time: 0.0,
+            state: PlayerState::default(),
+            playback_rate: 1.0,
+            volume: Volume::default(),
+            muted: false,
+            playback_quality: PlaybackQuality::default(),
+            playlist: Vec::new(),
+            playlist_index: None,
+        }
+    }
+}
+
+impl TryFrom<JsValue> for PlayerSnapshot {
+    type Error = serde_wasm_bindgen::Error;
+
+    fn try_from(snapshot: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(snapshot)
+    }
+}
+
+impl TryFrom<&PlayerSnapshot> for JsValue {
+    type Error = serde_wasm_bindgen::Error;
+
+    fn try_from(snapshot: &PlayerSnapshot) -> Result<Self, Self::Error> {
+        super::to_js_object(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::{borrow::ToOwned, vec};
+
+    use crate::PlaylistSource;
+
+    fn playing_snapshot() -> PlayerSnapshot {
+        PlayerSnapshot {
+            video_id: Some("M7lc1UVf-VE".to_owned()),
+            current_time: 83.5,
+            state: PlayerState::Playing,
+            playback_rate: 1.5,
+            volume: Volume::new(40),
+            muted: true,
+            playback_quality: PlaybackQuality::Hd720,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn snapshot_requests() {
+        let snapshot = playing_snapshot();
+
+        assert!(snapshot.is_playing());
+        assert_eq!(
+            Some(VideoRequest::by_id("M7lc1UVf-VE".to_owned()).start_seconds(83.5)),
+            snapshot.video_request()
+        );
+        assert_eq!(None, snapshot.playlist_request());
+
+        let snapshot = PlayerSnapshot {
+            state: PlayerState::Paused,
+            playlist: vec!["M7lc1UVf-VE".to_owned(), "bS4Q-WWyl3Q".to_owned()],
+            playlist_index: Some(1),
+            ..snapshot
+        };
+        let request = snapshot.playlist_request().unwrap();
+
+        assert!(!snapshot.is_playing());
+        assert_eq!(
+            PlaylistSource::Videos {
+                playlist: snapshot.playlist.clone()
+            },
+            request.source
+        );
+        assert_eq!(Some(1), request.index);
+        assert_eq!(Some(83.5), request.start_seconds);
+
+        assert_eq!(None, PlayerSnapshot::default().video_request());
+    }
+
+    #[test]
+    fn snapshot_serde() {
+        let snapshot = playing_snapshot();
+        let value = serde_json::to_value(&snapshot).unwrap();
+
+        assert_eq!(
+            serde_json::json!({
+                "videoId": "M7lc1UVf-VE",
+                "currentTime": 83.5,
+                "state": 1,
+                "playbackRate": 1.5,
+                "volume": 40,
+                "muted": true,
+                "playbackQuality": "hd720",
+                "playlist": [],
+                "playlistIndex": null,
+            }),
+            value
+        );
+        assert_eq!(snapshot, serde_json::from_value(value).unwrap());
+    }
+}
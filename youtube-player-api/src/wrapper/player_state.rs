@@ -1,15 +1,123 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use crate::TypeScript;
+
 // #[wasm_bindgen(typescript_type = "PlayerState")]
 // #[wasm_bindgen(js_name = PlayerState)]
-#[non_exhaustive]
-#[derive(Clone, Debug, PartialEq)]
-pub struct PlayerState;
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, TypeScript)]
+#[serde(from = "i32", into = "i32")]
+#[ts(const_enum)]
+pub enum PlayerState {
+    #[default]
+    #[ts(value = -1)]
+    Unstarted,
+    #[ts(value = 0)]
+    Ended,
+    #[ts(value = 1)]
+    Playing,
+    #[ts(value = 2)]
+    Paused,
+    #[ts(value = 3)]
+    Buffering,
+    #[ts(value = 5)]
+    Cued,
+    /// State not (yet) known by this library, e.g. introduced by a newer API version.
+    #[ts(skip)]
+    Unknown(i32),
+}
 
-// #[wasm_bindgen(js_class = PlayerState)]
 impl PlayerState {
-    pub const UNSTARTED: i32 = -1;
-    pub const ENDED: i32 = 0;
-    pub const PLAYING: i32 = 1;
-    pub const PAUSED: i32 = 2;
-    pub const BUFFERING: i32 = 3;
-    pub const CUED: i32 = 5;
+    /// All states documented by the Youtube API.
+    pub const KNOWN: [PlayerState; 6] = [
+        Self::Unstarted,
+        Self::Ended,
+        Self::Playing,
+        Self::Paused,
+        Self::Buffering,
+        Self::Cued,
+    ];
+
+    pub fn value(&self) -> i32 {
+        match self {
+            Self::Unstarted => -1,
+            Self::Ended => 0,
+            Self::Playing => 1,
+            Self::Paused => 2,
+            Self::Buffering => 3,
+            Self::Cued => 5,
+            Self::Unknown(state) => *state,
+        }
+    }
+}
+
+// conversion never fails, unknown values are kept in `PlayerState::Unknown`
+// and `TryFrom<i32>` is provided through the blanket implementation
+impl From<i32> for PlayerState {
+    fn from(state: i32) -> Self {
+        match state {
+            -1 => Self::Unstarted,
+            0 => Self::Ended,
+            1 => Self::Playing,
+            2 => Self::Paused,
+            3 => Self::Buffering,
+            5 => Self::Cued,
+            state => Self::Unknown(state),
+        }
+    }
+}
+
+impl From<PlayerState> for i32 {
+    fn from(state: PlayerState) -> Self {
+        state.value()
+    }
+}
+
+impl fmt::Display for PlayerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unstarted => f.write_str("unstarted"),
+            Self::Ended => f.write_str("ended"),
+            Self::Playing => f.write_str("playing"),
+            Self::Paused => f.write_str("paused"),
+            Self::Buffering => f.write_str("buffering"),
+            Self::Cued => f.write_str("cued"),
+            Self::Unknown(state) => write!(f, "unknown ({})", state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString;
+
+    #[test]
+    fn player_state_convert() {
+        for (state, value) in [
+            (PlayerState::Unstarted, -1),
+            (PlayerState::Ended, 0),
+            (PlayerState::Playing, 1),
+            (PlayerState::Paused, 2),
+            (PlayerState::Buffering, 3),
+            (PlayerState::Cued, 5),
+        ] {
+            assert_eq!(state, PlayerState::from(value));
+            assert_eq!(value, i32::from(state));
+        }
+    }
+
+    #[test]
+    fn player_state_unknown() {
+        assert_eq!(PlayerState::Unknown(4), PlayerState::from(4));
+        assert_eq!(PlayerState::Unknown(-2), PlayerState::from(-2));
+        assert_eq!(42, PlayerState::Unknown(42).value());
+    }
+
+    #[test]
+    fn player_state_display() {
+        assert_eq!("playing", PlayerState::Playing.to_string());
+        assert_eq!("unknown (4)", PlayerState::Unknown(4).to_string());
+    }
 }
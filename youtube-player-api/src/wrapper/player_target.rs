@@ -0,0 +1,35 @@
+use alloc::{borrow::ToOwned, string::String};
+use web_sys::Element;
+
+/// Element a player is created for, given directly or by its ID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlayerTarget {
+    /// ID of an element in the document, see `YtPlayer::new`.
+    Id(String),
+    /// Element which gets an ID assigned if it has none, see `YtPlayer::from_element`.
+    Element(Element),
+}
+
+impl From<&str> for PlayerTarget {
+    fn from(player_id: &str) -> Self {
+        Self::Id(player_id.to_owned())
+    }
+}
+
+impl From<String> for PlayerTarget {
+    fn from(player_id: String) -> Self {
+        Self::Id(player_id)
+    }
+}
+
+impl From<Element> for PlayerTarget {
+    fn from(element: Element) -> Self {
+        Self::Element(element)
+    }
+}
+
+impl From<&Element> for PlayerTarget {
+    fn from(element: &Element) -> Self {
+        Self::Element(element.clone())
+    }
+}
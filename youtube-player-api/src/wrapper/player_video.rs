@@ -0,0 +1,120 @@
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use super::PlaybackQuality;
+use crate::TypeScript;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TypeScript)]
+#[serde(untagged)]
+pub enum VideoSource {
+    /// Uses `loadVideoById`/`cueVideoById`.
+    Id {
+        #[serde(rename = "videoId")]
+        video_id: String,
+    },
+    /// Fully qualified player URL (`https://www.youtube.com/v/VIDEO_ID?version=3`),
+    /// uses `loadVideoByUrl`/`cueVideoByUrl`.
+    Url {
+        #[serde(rename = "mediaContentUrl")]
+        media_content_url: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TypeScript)]
+pub struct VideoRequest {
+    #[serde(flatten)]
+    pub source: VideoSource,
+    #[serde(rename = "startSeconds")]
+    pub start_seconds: Option<f64>,
+    #[serde(rename = "endSeconds")]
+    pub end_seconds: Option<f64>,
+    #[serde(rename = "suggestedQuality")]
+    pub suggested_quality: Option<PlaybackQuality>,
+}
+
+impl VideoRequest {
+    pub fn new(source: VideoSource) -> Self {
+        Self {
+            source,
+            start_seconds: None,
+            end_seconds: None,
+            suggested_quality: None,
+        }
+    }
+
+    pub fn by_id(video_id: String) -> Self {
+        Self::new(VideoSource::Id { video_id })
+    }
+
+    pub fn by_url(media_content_url: String) -> Self {
+        Self::new(VideoSource::Url { media_content_url })
+    }
+
+    pub fn start_seconds(mut self, start_seconds: f64) -> Self {
+        self.start_seconds = Some(start_seconds);
+        self
+    }
+
+    pub fn end_seconds(mut self, end_seconds: f64) -> Self {
+        self.end_seconds = Some(end_seconds);
+        self
+    }
+
+    pub fn suggested_quality(mut self, suggested_quality: PlaybackQuality) -> Self {
+        self.suggested_quality = Some(suggested_quality);
+        self
+    }
+}
+
+impl TryFrom<JsValue> for VideoRequest {
+    type Error = serde_wasm_bindgen::Error;
+
+    fn try_from(request: JsValue) -> Result<Self, Self::Error> {
+        serde_wasm_bindgen::from_value(request)
+    }
+}
+
+impl TryFrom<&VideoRequest> for JsValue {
+    type Error = serde_wasm_bindgen::Error;
+
+    fn try_from(request: &VideoRequest) -> Result<Self, Self::Error> {
+        super::to_js_object(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::borrow::ToOwned;
+
+    #[test]
+    fn video_request_default() {
+        let request = VideoRequest::by_id("cE0wfjsybIQ".to_owned());
+
+        assert_eq!(
+            VideoSource::Id {
+                video_id: "cE0wfjsybIQ".to_owned()
+            },
+            request.source
+        );
+        assert_eq!(None, request.start_seconds);
+        assert_eq!(None, request.end_seconds);
+        assert_eq!(None, request.suggested_quality);
+    }
+
+    #[test]
+    fn video_request_set() {
+        let media_content_url = "https://www.youtube.com/v/cE0wfjsybIQ?version=3".to_owned();
+        let request = VideoRequest::by_url(media_content_url.clone())
+            .start_seconds(30.0)
+            .end_seconds(90.5)
+            .suggested_quality(PlaybackQuality::Hd720);
+
+        assert_eq!(VideoSource::Url { media_content_url }, request.source);
+        assert_eq!(Some(30.0), request.start_seconds);
+        assert_eq!(Some(90.5), request.end_seconds);
+        assert_eq!(Some(PlaybackQuality::Hd720), request.suggested_quality);
+    }
+}
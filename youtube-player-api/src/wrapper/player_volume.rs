@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::TypeScript;
+
+/// Player volume, always clamped to the range accepted by the Youtube API (0–100).
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    TypeScript,
+)]
+#[serde(from = "f64", into = "u8")]
+#[ts(type = "number")]
+pub struct Volume(u8);
+
+impl Volume {
+    pub const MIN: Volume = Volume(0);
+    pub const MAX: Volume = Volume(100);
+
+    pub fn new(volume: i32) -> Self {
+        Self(volume.clamp(Self::MIN.0.into(), Self::MAX.0.into()) as u8)
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl From<u8> for Volume {
+    fn from(volume: u8) -> Self {
+        Self::new(volume.into())
+    }
+}
+
+impl From<i32> for Volume {
+    fn from(volume: i32) -> Self {
+        Self::new(volume)
+    }
+}
+
+impl From<f64> for Volume {
+    fn from(volume: f64) -> Self {
+        // JS numbers may be fractional or NaN, NaN is mapped to silence
+        if volume.is_nan() {
+            return Self::MIN;
+        }
+
+        Self::new(volume.round().clamp(i32::MIN.into(), i32::MAX.into()) as i32)
+    }
+}
+
+impl From<Volume> for u8 {
+    fn from(volume: Volume) -> Self {
+        volume.0
+    }
+}
+
+/// Payload of the synthetic `volumeChange` event, the Youtube API doesn't provide one on its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TypeScript)]
+pub struct VolumeChange {
+    pub volume: Volume,
+    pub muted: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn volume_clamped() {
+        assert_eq!(0, Volume::new(-20).value());
+        assert_eq!(0, Volume::new(0).value());
+        assert_eq!(42, Volume::new(42).value());
+        assert_eq!(100, Volume::new(100).value());
+        assert_eq!(100, Volume::new(250).value());
+
+        assert_eq!(Volume::MAX, Volume::from(u8::MAX));
+    }
+
+    #[test]
+    fn volume_from_number() {
+        assert_eq!(50, Volume::from(49.6).value());
+        assert_eq!(100, Volume::from(1e12).value());
+        assert_eq!(0, Volume::from(-1e12).value());
+        assert_eq!(0, Volume::from(f64::NAN).value());
+    }
+}
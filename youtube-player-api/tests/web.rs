@@ -0,0 +1,866 @@
+//! Headless browser tests against the fake Youtube API of module `test_support`.
+//!
+//! Run with `wasm-pack test --headless --firefox -- --features test-support`.
+
+#![cfg(target_arch = "wasm32")]
+
+use std::{cell::RefCell, rc::Rc};
+
+use js_sys::{Array, Object, Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+use youtube_player_api::{
+    init_yt_api, init_yt_api_with_options, test_support::MockYt, ApiLoadError, CommandError,
+    LoaderOptions, MonitorOptions, PendingCommandPolicy, PlaybackQuality, PlayerEvent, PlayerEvents,
+    PlayerInitError, PlayerOptions, PlayerRegistry, PlayerSnapshot, PlayerState, PlaylistRequest,
+    Seek, VideoRequest, Volume, YtPlayer,
+};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+type Calls = Rc<RefCell<Vec<JsValue>>>;
+
+/// JS handler function recording its first parameter.
+fn recording_handler() -> (JsValue, Calls) {
+    let calls: Calls = Rc::new(RefCell::new(vec![]));
+    let handler_calls = calls.clone();
+
+    let handler = Closure::wrap(Box::new(move |event: JsValue| {
+        handler_calls.borrow_mut().push(event);
+    }) as Box<dyn FnMut(JsValue)>);
+
+    (handler.into_js_value(), calls)
+}
+
+async fn sleep(milliseconds: i32) {
+    let timeout = Promise::new(&mut |resolve, _reject| {
+        web_sys::window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, milliseconds)
+            .unwrap();
+    });
+
+    JsFuture::from(timeout).await.unwrap();
+}
+
+fn create_player(mock: &MockYt, options: PlayerOptions) -> YtPlayer {
+    let player = YtPlayer::new("player", options.into());
+    mock.last_player().unwrap().fire_ready();
+
+    player
+}
+
+#[wasm_bindgen_test]
+async fn init_yt_api_resolves_with_yt_global() {
+    let _mock = MockYt::install();
+
+    let yt_global = JsFuture::from(init_yt_api()).await.unwrap();
+
+    assert!(Reflect::get(&yt_global, &"Player".into())
+        .unwrap()
+        .is_function());
+}
+
+#[wasm_bindgen_test]
+async fn init_yt_api_waits_for_ready_handler() {
+    // data URL doesn't need network access, the mock fires the ready handler instead
+    let api_loaded = init_yt_api_with_options(
+        LoaderOptions::new().script_url("data:text/javascript,".to_owned()),
+    );
+
+    let _mock = MockYt::install_with_ready_handler();
+
+    let yt_global = JsFuture::from(api_loaded).await.unwrap();
+
+    assert!(yt_global.is_object());
+}
+
+#[wasm_bindgen_test]
+async fn init_yt_api_shares_load() {
+    let script_url = "data:text/javascript,//shared-load";
+
+    let first_load =
+        init_yt_api_with_options(LoaderOptions::new().script_url(script_url.to_owned()));
+    let second_load = init_yt_api();
+
+    let _mock = MockYt::install_with_ready_handler();
+
+    assert!(JsFuture::from(first_load).await.is_ok());
+    assert!(JsFuture::from(second_load).await.is_ok());
+
+    let script_tags = web_sys::window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .get_elements_by_tag_name("script");
+
+    let api_scripts = (0..script_tags.length())
+        .filter_map(|index| script_tags.item(index))
+        .filter(|script_tag| script_tag.get_attribute("src").as_deref() == Some(script_url))
+        .count();
+
+    assert_eq!(1, api_scripts);
+}
+
+#[wasm_bindgen_test]
+async fn loader_state_is_kept_on_window() {
+    let _mock = MockYt::install();
+
+    JsFuture::from(init_yt_api()).await.unwrap();
+
+    let window = web_sys::window().unwrap();
+    let state = Reflect::get(&window, &"__youtubePlayerApiLoader".into()).unwrap();
+    let load = Reflect::get(&state, &"load".into()).unwrap();
+
+    assert_eq!(
+        JsValue::from(true),
+        Reflect::get(&load, &"result".into()).unwrap()
+    );
+}
+
+#[wasm_bindgen_test]
+fn new_player_passes_options() {
+    let mock = MockYt::install();
+
+    let _player = YtPlayer::new(
+        "player",
+        PlayerOptions::new().video_id("M7lc1UVf-VE".to_owned()).into(),
+    );
+
+    let mock_player = mock.last_player().unwrap();
+    let options = mock_player.options();
+
+    assert_eq!("player", mock_player.element_id());
+    assert_eq!(
+        Some("M7lc1UVf-VE".to_owned()),
+        Reflect::get(&options, &"videoId".into()).unwrap().as_string()
+    );
+
+    // wrapper listens to all API events, synthetic events stay in the wrapper
+    let events = Reflect::get(&options, &"events".into()).unwrap();
+
+    assert!(Reflect::get(&events, &"onReady".into()).unwrap().is_function());
+    assert!(Reflect::get(&events, &"onStateChange".into())
+        .unwrap()
+        .is_function());
+    assert!(Reflect::get(&events, &"onVolumeChange".into())
+        .unwrap()
+        .is_undefined());
+}
+
+#[wasm_bindgen_test]
+fn commands_are_queued_until_ready() {
+    let mock = MockYt::install();
+
+    let player = YtPlayer::new("player", PlayerOptions::new().into());
+    let mock_player = mock.last_player().unwrap();
+
+    player.seek_to(10.0, true).unwrap();
+    player.play_video().unwrap();
+
+    assert!(!player.is_ready());
+    assert!(mock_player.call_names().is_empty());
+
+    mock_player.fire_ready();
+
+    assert!(player.is_ready());
+    assert_eq!(vec!["seekTo", "playVideo"], mock_player.call_names());
+}
+
+#[wasm_bindgen_test]
+fn commands_are_dropped_until_ready() {
+    let mock = MockYt::install();
+
+    let options = PlayerOptions::new().pending_commands(PendingCommandPolicy::Drop);
+    let player = YtPlayer::new("player", options.into());
+    let mock_player = mock.last_player().unwrap();
+
+    player.play_video().unwrap();
+    mock_player.fire_ready();
+
+    assert!(mock_player.call_names().is_empty());
+
+    player.play_video().unwrap();
+
+    assert_eq!(vec!["playVideo"], mock_player.call_names());
+}
+
+#[wasm_bindgen_test]
+fn commands_are_rejected_until_ready() {
+    let mock = MockYt::install();
+
+    let options = PlayerOptions::new().pending_commands(PendingCommandPolicy::Error);
+    let player = YtPlayer::new("player", options.into());
+    let mock_player = mock.last_player().unwrap();
+
+    assert_eq!(Err(CommandError::NotReady), player.play_video());
+    assert_eq!(Err(CommandError::NotReady), player.set_volume(Volume::new(40)));
+
+    // rejected commands throw an `Error` in JS
+    assert!(JsValue::from(CommandError::NotReady).is_instance_of::<js_sys::Error>());
+
+    mock_player.fire_ready();
+
+    assert!(mock_player.call_names().is_empty());
+    assert_eq!(Ok(()), player.play_video());
+    assert_eq!(vec!["playVideo"], mock_player.call_names());
+}
+
+#[wasm_bindgen_test]
+fn commands_and_getters_use_api() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+    let mock_player = mock.last_player().unwrap();
+
+    player
+        .load_video(&VideoRequest::by_id("M7lc1UVf-VE".to_owned()).start_seconds(30.0))
+        .unwrap();
+
+    let video = mock_player.last_call_args("loadVideoById").unwrap().get(0);
+
+    assert_eq!(
+        Some("M7lc1UVf-VE".to_owned()),
+        Reflect::get(&video, &"videoId".into()).unwrap().as_string()
+    );
+    assert_eq!(
+        Some(30.0),
+        Reflect::get(&video, &"startSeconds".into()).unwrap().as_f64()
+    );
+
+    player.set_size(640.0, 360.0).unwrap();
+
+    assert_eq!(
+        vec![JsValue::from(640), JsValue::from(360)],
+        mock_player.last_call_args("setSize").unwrap().to_vec()
+    );
+
+    mock_player.set_return("getPlayerState", 2.into());
+
+    assert_eq!(PlayerState::Paused, player.get_player_state());
+}
+
+#[wasm_bindgen_test]
+fn playlist_object_is_passed_to_api() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+    let mock_player = mock.last_player().unwrap();
+
+    let request = PlaylistRequest::playlist("PLabcdefghij".to_owned())
+        .index(2)
+        .start_seconds(5.0);
+
+    player.load_playlist(&request).unwrap();
+
+    let playlist = mock_player.last_call_args("loadPlaylist").unwrap().get(0);
+
+    assert_eq!(
+        Some("playlist".to_owned()),
+        Reflect::get(&playlist, &"listType".into()).unwrap().as_string()
+    );
+    assert_eq!(
+        Some("PLabcdefghij".to_owned()),
+        Reflect::get(&playlist, &"list".into()).unwrap().as_string()
+    );
+    assert_eq!(Some(2.0), Reflect::get(&playlist, &"index".into()).unwrap().as_f64());
+    assert_eq!(
+        Some(5.0),
+        Reflect::get(&playlist, &"startSeconds".into()).unwrap().as_f64()
+    );
+}
+
+#[wasm_bindgen_test]
+fn dispatch_event_without_target() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+
+    let (handler, calls) = recording_handler();
+    player.on(PlayerEvents::STATE_CHANGE, handler);
+
+    mock.last_player().unwrap().fire("stateChange", 1.into());
+
+    let calls = calls.borrow();
+    assert_eq!(1, calls.len());
+
+    let event = &calls[0];
+    assert_eq!(Some(1.0), Reflect::get(event, &"data".into()).unwrap().as_f64());
+    assert!(!Reflect::has(event, &"target".into()).unwrap());
+}
+
+#[wasm_bindgen_test]
+fn handlers_from_options_are_called() {
+    let mock = MockYt::install();
+
+    let (handler, calls) = recording_handler();
+    let events = Object::new();
+    Reflect::set(&events, &"stateChange".into(), &handler).unwrap();
+
+    let options: Object = PlayerOptions::new().into();
+    Reflect::set(&options, &"events".into(), &events).unwrap();
+
+    let _player = YtPlayer::new("player", options);
+    let mock_player = mock.last_player().unwrap();
+
+    mock_player.fire_ready();
+    mock_player.fire("stateChange", 2.into());
+
+    assert_eq!(1, calls.borrow().len());
+}
+
+#[wasm_bindgen_test]
+fn typed_event_handler() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+
+    let states = Rc::new(RefCell::new(vec![]));
+    let handler_states = states.clone();
+
+    player.on_event(PlayerEvents::STATE_CHANGE, move |event| {
+        if let PlayerEvent::StateChange(state) = event {
+            handler_states.borrow_mut().push(state);
+        }
+    });
+
+    mock.last_player().unwrap().fire("stateChange", 1.into());
+
+    assert_eq!(vec![PlayerState::Playing], *states.borrow());
+}
+
+#[wasm_bindgen_test]
+fn off_removes_namespace_hierarchy() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+    let mock_player = mock.last_player().unwrap();
+
+    let (sync_handler, sync_calls) = recording_handler();
+    let (drift_handler, drift_calls) = recording_handler();
+    let (ui_handler, ui_calls) = recording_handler();
+
+    player.on("stateChange.sync", sync_handler);
+    player.on("stateChange.sync.drift", drift_handler);
+    player.on("stateChange.ui", ui_handler);
+
+    player.off("stateChange.sync.drift");
+    mock_player.fire("stateChange", 1.into());
+
+    assert_eq!(1, sync_calls.borrow().len());
+    assert_eq!(0, drift_calls.borrow().len());
+    assert_eq!(1, ui_calls.borrow().len());
+
+    // empty event name removes namespace from all events
+    player.off(".sync");
+    mock_player.fire("stateChange", 2.into());
+
+    assert_eq!(1, sync_calls.borrow().len());
+    assert_eq!(2, ui_calls.borrow().len());
+
+    player.off("stateChange");
+    mock_player.fire("stateChange", 1.into());
+
+    assert_eq!(2, ui_calls.borrow().len());
+}
+
+#[wasm_bindgen_test]
+fn once_and_off_id() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+    let mock_player = mock.last_player().unwrap();
+
+    let (once_handler, once_calls) = recording_handler();
+    let (handler, calls) = recording_handler();
+
+    player.once(PlayerEvents::STATE_CHANGE, once_handler);
+    let handler_id = player.on(PlayerEvents::STATE_CHANGE, handler).unwrap();
+
+    mock_player.fire("stateChange", 1.into());
+    mock_player.fire("stateChange", 2.into());
+
+    assert_eq!(1, once_calls.borrow().len());
+    assert_eq!(2, calls.borrow().len());
+
+    assert!(player.off_id(handler_id));
+    assert!(!player.off_id(handler_id));
+
+    mock_player.fire("stateChange", 1.into());
+
+    assert_eq!(2, calls.borrow().len());
+}
+
+#[wasm_bindgen_test]
+fn removed_typed_handlers_are_released() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+    let mock_player = mock.last_player().unwrap();
+
+    // every handler closure holds a reference until it's released
+    let captured = Rc::new(());
+
+    let handler_captured = captured.clone();
+    let handler_id = player
+        .on_event(PlayerEvents::STATE_CHANGE, move |_| {
+            let _captured = &handler_captured;
+        })
+        .unwrap();
+
+    assert_eq!(2, Rc::strong_count(&captured));
+    assert!(player.off_id(handler_id));
+    assert_eq!(1, Rc::strong_count(&captured));
+
+    let handler_captured = captured.clone();
+    player.on_event("stateChange.sync", move |_| {
+        let _captured = &handler_captured;
+    });
+    player.off(".sync");
+
+    assert_eq!(1, Rc::strong_count(&captured));
+
+    let handler_captured = captured.clone();
+    player.once_event(PlayerEvents::STATE_CHANGE, move |_| {
+        let _captured = &handler_captured;
+    });
+
+    assert_eq!(2, Rc::strong_count(&captured));
+
+    mock_player.fire("stateChange", 1.into());
+
+    assert_eq!(1, Rc::strong_count(&captured));
+}
+
+#[wasm_bindgen_test]
+fn wildcard_receives_event_name() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+
+    let (handler, calls) = recording_handler();
+    player.on(PlayerEvents::WILDCARD, handler);
+
+    mock.last_player().unwrap().fire("playbackRateChange", 2.into());
+    player.mute().unwrap();
+
+    let calls = calls.borrow();
+
+    assert_eq!(
+        vec![
+            Some(PlayerEvents::PLAYBACK_RATE_CHANGE.to_owned()),
+            Some(PlayerEvents::VOLUME_CHANGE.to_owned())
+        ],
+        calls.iter().map(JsValue::as_string).collect::<Vec<_>>()
+    );
+}
+
+#[wasm_bindgen_test]
+fn destroy_releases_player() {
+    let mock = MockYt::install();
+    let mut player = create_player(&mock, PlayerOptions::new());
+    let mock_player = mock.last_player().unwrap();
+
+    let (handler, calls) = recording_handler();
+    player.on(PlayerEvents::STATE_CHANGE, handler);
+
+    player.destroy();
+
+    assert_eq!(Err(CommandError::Unavailable), player.play_video());
+    assert!(!player.is_ready());
+    assert_eq!(vec!["destroy"], mock_player.call_names());
+    assert_eq!(0, calls.borrow().len());
+}
+
+#[wasm_bindgen_test]
+fn commands_are_rejected_without_player() {
+    // Youtube API isn't loaded, so the player can't be constructed
+    let options = PlayerOptions::new().pending_commands(PendingCommandPolicy::Error);
+    let player = YtPlayer::new("player", options.into());
+
+    assert_eq!(Err(CommandError::Unavailable), player.play_video());
+    assert_eq!(
+        Err(CommandError::Unavailable),
+        player.load_video(&VideoRequest::by_id("M7lc1UVf-VE".to_owned()))
+    );
+}
+
+#[wasm_bindgen_test]
+async fn time_updates_while_playing() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new().time_update_interval(10));
+    let mock_player = mock.last_player().unwrap();
+
+    let events = Rc::new(RefCell::new(vec![]));
+    let handler_events = events.clone();
+
+    player.on_event(PlayerEvents::WILDCARD, move |event| {
+        handler_events.borrow_mut().push(event);
+    });
+
+    mock_player.set_return("getPlayerState", 2.into());
+    mock_player.set_return("getCurrentTime", 12.5.into());
+    mock_player.set_return("getVideoLoadedFraction", 0.5.into());
+
+    sleep(50).await;
+
+    // paused
+    assert!(events.borrow().is_empty());
+
+    mock_player.set_return("getPlayerState", 1.into());
+
+    sleep(50).await;
+
+    let events = events.borrow();
+
+    assert!(events.contains(&PlayerEvent::TimeUpdate(12.5)));
+    assert_eq!(
+        1,
+        events
+            .iter()
+            .filter(|event| **event == PlayerEvent::Progress(0.5))
+            .count()
+    );
+}
+
+#[wasm_bindgen_test]
+async fn playback_monitor_detects_seek() {
+    let mock = MockYt::install();
+    let options = PlayerOptions::new().playback_monitor(MonitorOptions::new().interval(10));
+    let player = create_player(&mock, options);
+    let mock_player = mock.last_player().unwrap();
+
+    let seeks = Rc::new(RefCell::new(vec![]));
+    let handler_seeks = seeks.clone();
+
+    player.on_event(PlayerEvents::SEEKED, move |event| {
+        if let PlayerEvent::Seeked(seek) = event {
+            handler_seeks.borrow_mut().push(seek);
+        }
+    });
+
+    mock_player.set_return("getPlayerState", 2.into());
+    mock_player.set_return("getCurrentTime", 10.into());
+    mock_player.set_return("getPlaybackRate", 1.into());
+
+    sleep(50).await;
+
+    mock_player.set_return("getCurrentTime", 60.into());
+
+    sleep(50).await;
+
+    assert_eq!(vec![Seek { from: 10.0, to: 60.0 }], *seeks.borrow());
+}
+
+#[wasm_bindgen_test]
+fn registry_one_player_per_element() {
+    let mock = MockYt::install();
+    let mut registry = PlayerRegistry::new();
+
+    registry.create("first", PlayerOptions::new()).unwrap();
+    registry.create("second", PlayerOptions::new()).unwrap();
+
+    assert_eq!(
+        PlayerInitError::ElementInUse,
+        registry
+            .create("first", PlayerOptions::new())
+            .unwrap_err()
+    );
+    assert_eq!(2, registry.len());
+    assert_eq!(2, mock.players().len());
+    assert_eq!("second", registry.get("second").unwrap().id());
+
+    let mut ids: Vec<&str> = registry.ids().collect();
+    ids.sort();
+
+    assert_eq!(vec!["first", "second"], ids);
+}
+
+#[wasm_bindgen_test]
+fn registry_rejects_failed_player() {
+    let mut registry = PlayerRegistry::new();
+
+    // Youtube API isn't loaded
+    assert_eq!(
+        PlayerInitError::ApiLoad(ApiLoadError::YtGlobalMissing),
+        registry
+            .create("player", PlayerOptions::new())
+            .unwrap_err()
+    );
+    assert!(!registry.contains("player"));
+
+    let mock = MockYt::install();
+
+    registry.create("player", PlayerOptions::new()).unwrap();
+
+    assert_eq!(1, mock.players().len());
+}
+
+#[wasm_bindgen_test]
+async fn registry_create_async_awaits_api_load() {
+    let mut registry = PlayerRegistry::new();
+
+    let api_loaded = init_yt_api_with_options(
+        LoaderOptions::new().script_url("data:text/javascript,".to_owned()),
+    );
+    let mock = MockYt::install_with_ready_handler();
+
+    let player = registry
+        .create_async("player", PlayerOptions::new())
+        .await
+        .unwrap();
+
+    assert_eq!("player", player.id());
+    assert_eq!(1, mock.players().len());
+    assert!(JsFuture::from(api_loaded).await.is_ok());
+}
+
+#[wasm_bindgen_test]
+fn registry_insert_returns_rejected_player() {
+    let mock = MockYt::install();
+    let mut registry = PlayerRegistry::new();
+
+    registry.create("player", PlayerOptions::new()).unwrap();
+
+    let (error, player) = registry
+        .insert(YtPlayer::new("player", PlayerOptions::new().into()))
+        .unwrap_err();
+
+    assert_eq!(PlayerInitError::ElementInUse, error);
+    assert_eq!("player", player.id());
+
+    // rejected player is still alive
+    let rejected_mock_player = mock.last_player().unwrap();
+    assert!(rejected_mock_player.call_names().is_empty());
+
+    drop(player);
+
+    assert_eq!(vec!["destroy"], rejected_mock_player.call_names());
+}
+
+#[wasm_bindgen_test]
+fn registry_pause_and_destroy_all() {
+    let mock = MockYt::install();
+    let mut registry = PlayerRegistry::new();
+
+    registry.create("first", PlayerOptions::new()).unwrap();
+    registry.create("second", PlayerOptions::new()).unwrap();
+
+    for mock_player in mock.players() {
+        mock_player.fire_ready();
+    }
+
+    registry.pause_all();
+    registry.destroy_all();
+
+    assert!(registry.is_empty());
+
+    for mock_player in mock.players() {
+        assert_eq!(vec!["pauseVideo", "destroy"], mock_player.call_names());
+    }
+}
+
+#[wasm_bindgen_test]
+fn registry_pause_all_skips_pending_players() {
+    let mock = MockYt::install();
+    let mut registry = PlayerRegistry::new();
+
+    registry.create("player", PlayerOptions::new()).unwrap();
+    registry.pause_all();
+
+    let mock_player = mock.last_player().unwrap();
+    mock_player.fire_ready();
+
+    assert!(mock_player.call_names().is_empty());
+}
+
+#[wasm_bindgen_test]
+fn player_from_element_gets_id() {
+    let mock = MockYt::install();
+    let document = web_sys::window().unwrap().document().unwrap();
+    let element = document.create_element("div").unwrap();
+
+    let player = YtPlayer::from_element(&element, PlayerOptions::new().into());
+    let mock_player = mock.last_player().unwrap();
+
+    assert!(!element.id().is_empty());
+    assert_eq!(element.id(), player.id());
+    assert_eq!(JsValue::from(&element), mock_player.target());
+}
+
+#[wasm_bindgen_test]
+async fn create_async_accepts_element() {
+    let mock = MockYt::install();
+    let document = web_sys::window().unwrap().document().unwrap();
+    let element = document.create_element("div").unwrap();
+
+    let created: Rc<RefCell<Option<YtPlayer>>> = Rc::new(RefCell::new(None));
+    let created_player = created.clone();
+    let target = element.clone();
+
+    wasm_bindgen_futures::spawn_local(async move {
+        let player = YtPlayer::create_async(&target, PlayerOptions::new()).await.unwrap();
+        created_player.borrow_mut().replace(player);
+    });
+
+    sleep(0).await;
+
+    let mock_player = mock.last_player().unwrap();
+    assert_eq!(JsValue::from(&element), mock_player.target());
+    assert!(created.borrow().is_none());
+
+    mock_player.fire_ready();
+    sleep(0).await;
+
+    let player = created.borrow_mut().take().unwrap();
+
+    assert!(player.is_ready());
+    assert!(!element.id().is_empty());
+    assert_eq!(element.id(), player.id());
+}
+
+#[wasm_bindgen_test]
+fn player_in_container_appends_element() {
+    let mock = MockYt::install();
+    let document = web_sys::window().unwrap().document().unwrap();
+    let parent = document.create_element("div").unwrap();
+
+    let player = YtPlayer::in_container(&parent, PlayerOptions::new().into()).unwrap();
+    let container = parent.first_element_child().unwrap();
+
+    assert_eq!(1, parent.child_element_count());
+    assert_eq!(container.id(), player.id());
+    assert_eq!(JsValue::from(&container), mock.last_player().unwrap().target());
+}
+
+#[wasm_bindgen_test]
+async fn aspect_ratio_fits_parent_width() {
+    let mock = MockYt::install();
+    let document = web_sys::window().unwrap().document().unwrap();
+    let parent = document.create_element("div").unwrap();
+    let iframe = document.create_element("iframe").unwrap();
+
+    parent.set_attribute("style", "width: 640px").unwrap();
+    parent.append_child(&iframe).unwrap();
+    document.body().unwrap().append_child(&parent).unwrap();
+
+    let player = YtPlayer::new(
+        "player",
+        PlayerOptions::new().aspect_ratio(16.0 / 9.0).into(),
+    );
+    let mock_player = mock.last_player().unwrap();
+
+    mock_player.set_return("getIframe", iframe.clone().into());
+    mock_player.fire_ready();
+
+    assert_eq!(Some(iframe.unchecked_into()), player.iframe());
+
+    sleep(50).await;
+
+    let size = mock_player.last_call_args("setSize").unwrap();
+
+    assert_eq!(JsValue::from(640), size.get(0));
+    assert_eq!(JsValue::from(360), size.get(1));
+
+    player.set_aspect_ratio(None);
+    parent.remove();
+}
+
+#[wasm_bindgen_test]
+fn snapshot_reads_player() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+    let mock_player = mock.last_player().unwrap();
+
+    let video_data = Object::new();
+    Reflect::set(&video_data, &"video_id".into(), &"M7lc1UVf-VE".into()).unwrap();
+
+    mock_player.set_return("getVideoData", video_data.into());
+    mock_player.set_return("getCurrentTime", 83.5.into());
+    mock_player.set_return("getPlayerState", 1.into());
+    mock_player.set_return("getPlaybackRate", 1.5.into());
+    mock_player.set_return("getVolume", 40.into());
+    mock_player.set_return("isMuted", true.into());
+    mock_player.set_return("getPlaybackQuality", "hd720".into());
+
+    let snapshot = player.snapshot();
+
+    assert_eq!(Some("M7lc1UVf-VE".to_owned()), snapshot.video_id);
+    assert_eq!(83.5, snapshot.current_time);
+    assert_eq!(PlayerState::Playing, snapshot.state);
+    assert_eq!(1.5, snapshot.playback_rate);
+    assert_eq!(Volume::new(40), snapshot.volume);
+    assert!(snapshot.muted);
+    assert_eq!(PlaybackQuality::Hd720, snapshot.playback_quality);
+    assert!(snapshot.playlist.is_empty());
+    assert_eq!(None, snapshot.playlist_index);
+}
+
+#[wasm_bindgen_test]
+fn restore_waits_for_ready() {
+    let mock = MockYt::install();
+
+    let options = PlayerOptions::new().pending_commands(PendingCommandPolicy::Drop);
+    let player = YtPlayer::new("player", options.into());
+    let mock_player = mock.last_player().unwrap();
+
+    let snapshot = PlayerSnapshot {
+        video_id: Some("M7lc1UVf-VE".to_owned()),
+        current_time: 83.5,
+        state: PlayerState::Playing,
+        volume: Volume::new(40),
+        muted: true,
+        playback_rate: 1.5,
+        ..Default::default()
+    };
+
+    player.restore(&snapshot).unwrap();
+
+    assert!(mock_player.call_names().is_empty());
+
+    mock_player.fire_ready();
+
+    let video = mock_player.last_call_args("loadVideoById").unwrap().get(0);
+
+    assert_eq!(
+        Some("M7lc1UVf-VE".to_owned()),
+        Reflect::get(&video, &"videoId".into()).unwrap().as_string()
+    );
+    assert_eq!(
+        Some(83.5),
+        Reflect::get(&video, &"startSeconds".into()).unwrap().as_f64()
+    );
+    assert_eq!(
+        vec![JsValue::from(40)],
+        mock_player.last_call_args("setVolume").unwrap().to_vec()
+    );
+    assert!(mock_player.last_call_args("mute").is_some());
+    assert_eq!(
+        vec![JsValue::from(1.5)],
+        mock_player.last_call_args("setPlaybackRate").unwrap().to_vec()
+    );
+}
+
+#[wasm_bindgen_test]
+fn restore_cues_paused_playlist() {
+    let mock = MockYt::install();
+    let player = create_player(&mock, PlayerOptions::new());
+    let mock_player = mock.last_player().unwrap();
+
+    let snapshot = PlayerSnapshot {
+        video_id: Some("bS4Q-WWyl3Q".to_owned()),
+        current_time: 12.0,
+        state: PlayerState::Paused,
+        playlist: vec!["M7lc1UVf-VE".to_owned(), "bS4Q-WWyl3Q".to_owned()],
+        playlist_index: Some(1),
+        ..Default::default()
+    };
+
+    player.restore(&snapshot).unwrap();
+
+    let call_names = mock_player.call_names();
+
+    assert!(call_names.contains(&"cuePlaylist".to_owned()));
+    assert!(!call_names.contains(&"loadVideoById".to_owned()));
+    assert!(!call_names.contains(&"cueVideoById".to_owned()));
+
+    let playlist = mock_player.last_call_args("cuePlaylist").unwrap();
+
+    assert_eq!(2, Array::from(&playlist.get(0)).length());
+    assert_eq!(JsValue::from(1), playlist.get(1));
+    assert_eq!(JsValue::from(12), playlist.get(2));
+}